@@ -1,7 +1,9 @@
 use clap::Parser;
 
 use diet_mac_and_cheese::backend_trait::BackendT;
-use diet_mac_and_cheese::ram::{self, Bounded};
+use diet_mac_and_cheese::ram::{self, Bounded, RamTransport};
+#[cfg(feature = "tokio")]
+use diet_mac_and_cheese::ram::AsyncTransportBridge;
 use diet_mac_and_cheese::{DietMacAndCheeseProver, DietMacAndCheeseVerifier};
 use eyre::Result;
 use log::info;
@@ -25,6 +27,19 @@ use ocelot::svole::{
 const DEFAULT_ADDR: &str = "127.0.0.1:5527";
 const DEFAULT_LPN: LpnSize = LpnSize::Medium;
 
+/// Split a [`RamTransport`] into the buffered, byte-counted channel halves
+/// used throughout this binary. Swapping the blocking `TcpStream` path for
+/// another [`RamTransport`] impl (e.g. a Unix socket) never touches the
+/// `ram` proving path, which only ever talks to an `AbstractChannel`.
+fn socket_to_channel<S: RamTransport>(
+    stream: S,
+) -> Result<Channel<CntReader<BufReader<S::Reader>>, CntWriter<BufWriter<S::Writer>>>> {
+    let (reader, writer) = stream.split()?;
+    let reader = CntReader::new(BufReader::new(reader));
+    let writer = CntWriter::new(BufWriter::new(writer));
+    Ok(Channel::new(reader, writer))
+}
+
 /// Lpn params as small, medium or large.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum)]
 pub(crate) enum LpnSize {
@@ -55,7 +70,7 @@ pub(crate) enum Prover {
 }
 
 /// Cli.
-#[derive(Parser)]
+#[derive(Parser, Clone)]
 #[clap(name = "Dora RAM")]
 #[clap(version = "0.1")]
 pub(crate) struct Cli {
@@ -87,14 +102,68 @@ pub(crate) struct Cli {
     pub prover: bool,
 }
 
+/// Drive a single verifier session to completion over an already-accepted
+/// transport. Pulled out of `run_text` so both the blocking accept loop and
+/// the tokio-multiplexed accept loop (behind the `tokio` feature) can share
+/// it.
+fn run_verifier_session<S: RamTransport>(stream: S, args: &Cli) -> Result<()> {
+    let (lpn_setup, lpn_expand) = map_lpn_size(&args.lpn);
+
+    let rng = AesRng::new();
+    let mut channel = socket_to_channel(stream)?;
+
+    let start = Instant::now();
+
+    let mut verifier: DietMacAndCheeseVerifier<F61p, F61p, _> = DietMacAndCheeseVerifier::init(
+        &mut channel,
+        rng,
+        lpn_setup,
+        lpn_expand,
+        args.nobatching,
+    )
+    .unwrap();
+
+    info!("init time: {:?}", start.elapsed());
+    let start = Instant::now();
+
+    for run in 0..args.ram_runs {
+        info!("run {}/{}", run, args.ram_runs);
+
+        let mut ram = ram::Verifier::<F61p, F61p, _, _, 1, 1, 3, 2, 4>::new(
+            &mut verifier,
+            Bounded::new(args.ram_size),
+        );
+
+        for _i in 0..args.ram_steps {
+            let addr = verifier.input_private(None).unwrap();
+            let value = ram.remove(&mut verifier, &[addr]).unwrap();
+            ram.insert(&mut verifier, &[addr], &value).unwrap();
+        }
+        info!("finalizing ram");
+        ram.finalize(&mut verifier).unwrap();
+    }
+    info!("finalizing verifier");
+    verifier.finalize().unwrap();
+
+    info!("ram-size {}", args.ram_size);
+    info!("ram-steps {}", args.ram_steps);
+    info!("ram-runs {}", args.ram_runs);
+    info!("time ram exec: {:?}", start.elapsed());
+    let sent = channel.clone().writer().borrow().count();
+    let recv = channel.clone().reader().borrow().count();
+    info!("bytes sent: {}", sent);
+    info!("bytes recv: {}", recv);
+    info!("bytes total: {}", sent + recv);
+    info!("VERIFIER DONE!");
+    Ok(())
+}
+
 // Run with relation in text format
 fn run_text(args: &Cli) -> Result<()> {
     let start = Instant::now();
 
     info!("time reading ins/wit/rel: {:?}", start.elapsed());
 
-    let (lpn_setup, lpn_expand) = map_lpn_size(&args.lpn);
-
     match args.prover {
         false => {
             // Verifier mode
@@ -102,65 +171,14 @@ fn run_text(args: &Cli) -> Result<()> {
             match listener.accept() {
                 Ok((stream, _addr)) => {
                     info!("connection received");
-                    let reader = BufReader::new(stream.try_clone()?);
-                    let writer = BufWriter::new(stream);
-
-                    let reader = CntReader::new(reader);
-                    let writer = CntWriter::new(writer);
-
-                    let rng = AesRng::new();
-                    let mut channel = Channel::new(reader, writer);
-
-                    let start = Instant::now();
-
-                    let mut verifier: DietMacAndCheeseVerifier<F61p, F61p, _> =
-                        DietMacAndCheeseVerifier::init(
-                            &mut channel,
-                            rng,
-                            lpn_setup,
-                            lpn_expand,
-                            args.nobatching,
-                        )
-                        .unwrap();
-
-                    info!("init time: {:?}", start.elapsed());
-                    let start = Instant::now();
-
-                    for run in 0..args.ram_runs {
-                        info!("run {}/{}", run, args.ram_runs);
-
-                        let mut ram = ram::Verifier::<F61p, F61p, _, _, 1, 1, 3, 2, 4>::new(
-                            &mut verifier,
-                            Bounded::new(args.ram_size),
-                        );
-
-                        for _i in 0..args.ram_steps {
-                            let addr = verifier.input_private(None).unwrap();
-                            let value = ram.remove(&mut verifier, &[addr]).unwrap();
-                            ram.insert(&mut verifier, &[addr], &value).unwrap();
-                        }
-                        info!("finalizing ram");
-                        ram.finalize(&mut verifier).unwrap();
-                    }
-                    info!("finalizing verifier");
-                    verifier.finalize().unwrap();
-
-                    info!("ram-size {}", args.ram_size);
-                    info!("ram-steps {}", args.ram_steps);
-                    info!("ram-runs {}", args.ram_runs);
-                    info!("time ram exec: {:?}", start.elapsed());
-                    let sent = channel.clone().writer().borrow().count();
-                    let recv = channel.clone().reader().borrow().count();
-                    info!("bytes sent: {}", sent);
-                    info!("bytes recv: {}", recv);
-                    info!("bytes total: {}", sent + recv);
-                    info!("VERIFIER DONE!");
+                    run_verifier_session(stream, args)?;
                 }
                 Err(e) => info!("couldn't get client: {:?}", e),
             }
         }
         true => {
             // Prover mode
+            let (lpn_setup, lpn_expand) = map_lpn_size(&args.lpn);
             let stream;
             loop {
                 let c = TcpStream::connect(args.connection_addr.clone());
@@ -173,13 +191,7 @@ fn run_text(args: &Cli) -> Result<()> {
                 }
             }
 
-            let reader = BufReader::new(stream.try_clone()?);
-            let writer = BufWriter::new(stream);
-
-            let reader = CntReader::new(reader);
-            let writer = CntWriter::new(writer);
-
-            let mut channel = Channel::new(reader, writer);
+            let mut channel = socket_to_channel(stream)?;
 
             let rng = AesRng::new();
             let start = Instant::now();
@@ -235,6 +247,41 @@ fn run_text(args: &Cli) -> Result<()> {
     Ok(())
 }
 
+/// Accept verifier connections on a tokio runtime, handing each one off to a
+/// blocking task running the existing synchronous proving loop.
+///
+/// This serves many concurrent proving sessions from a single process
+/// instead of one blocking connection per process: the accept loop is async
+/// (so it can multiplex connections), and each accepted connection stays on
+/// tokio's I/O driver for the rest of its session too, via
+/// [`AsyncTransportBridge`], since `DietMacAndCheeseProver`/
+/// `DietMacAndCheeseVerifier` only speak `AbstractChannel`, not
+/// `AsyncRamTransport`, in this tree. `spawn_blocking` still parks a thread
+/// per in-flight session (the protocol driver itself is synchronous), but
+/// the socket is never demoted to a blocking `std::net::TcpStream`.
+#[cfg(feature = "tokio")]
+async fn run_async(args: Cli) -> Result<()> {
+    if args.prover {
+        // The prover side of a single session is still a single blocking
+        // connection; only the verifier multiplexes.
+        return tokio::task::spawn_blocking(move || run_text(&args)).await?;
+    }
+
+    let listener = tokio::net::TcpListener::bind(&args.connection_addr).await?;
+    loop {
+        let (stream, _addr) = listener.accept().await?;
+        info!("connection received");
+        let stream = AsyncTransportBridge::new(stream);
+        let args = args.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = run_verifier_session(stream, &args) {
+                info!("session failed: {:?}", e);
+            }
+        });
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
 fn run(args: &Cli) -> Result<()> {
     if args.prover {
         info!("prover mode");
@@ -247,6 +294,7 @@ fn run(args: &Cli) -> Result<()> {
     run_text(args)
 }
 
+#[cfg(not(feature = "tokio"))]
 fn main() -> Result<()> {
     // if log-level `RUST_LOG` not already set, then set to info
     match env::var("RUST_LOG") {
@@ -260,3 +308,21 @@ fn main() -> Result<()> {
 
     run(&cli)
 }
+
+#[cfg(feature = "tokio")]
+#[tokio::main]
+async fn main() -> Result<()> {
+    // if log-level `RUST_LOG` not already set, then set to info
+    match env::var("RUST_LOG") {
+        Ok(val) => println!("loglvl: {}", val),
+        Err(_) => env::set_var("RUST_LOG", "info"),
+    };
+
+    pretty_env_logger::init_timed();
+
+    let cli = Cli::parse();
+    info!("addr: {:?}", cli.connection_addr);
+    info!("lpn: {:?}", cli.lpn);
+
+    run_async(cli).await
+}
@@ -3,15 +3,17 @@
 //! Diet Mac'n'Cheese backends supporting SIEVE IR0+ with multiple fields.
 
 use crate::backend_trait::Party;
+use crate::circuit_ir;
 use crate::circuit_ir::{
-    CircInputs, FunStore, FuncDecl, GateM, TypeSpecification, TypeStore, WireCount, WireId,
-    WireRange,
+    CircInputs, FunStore, FuncDecl, GateM, TypeSpecification, TypeStore, ValidationError,
+    WireCount, WireId, WireRange,
 };
 use crate::dora::{Disjunction, DoraProver, DoraVerifier};
 use crate::edabits::{EdabitsProver, EdabitsVerifier, ProverConv, VerifierConv};
 use crate::homcom::{FComProver, FComVerifier};
 use crate::homcom::{MacProver, MacVerifier};
 use crate::memory::Memory;
+use crate::plugins::ecdsa::EcdsaVerifyV0;
 use crate::plugins::{DisjunctionBody, PluginExecution, RamOperation};
 use crate::read_sieveir_phase2::BufRelation;
 use crate::text_reader::TextRelation;
@@ -34,11 +36,32 @@ use std::fmt::Debug;
 use std::io::{Read, Seek};
 use std::marker::PhantomData;
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
 use swanky_field::{FiniteField, FiniteRing, IsSubFieldOf, PrimeFiniteField};
 use swanky_field_binary::{F40b, F2};
 use swanky_field_f61p::F61p;
 use swanky_field_ff_primes::{F128p, F384p, F384q, Secp256k1, Secp256k1order};
 
+// Bit-level hash gadgets (SHA-256/BLAKE2s) built directly on the `dmc_f2`
+// sub-backend; see `DietMacAndCheeseConvProver::sha256` and friends below.
+// `compress` is also reused by the `Sha256V0` plugin in `crate::plugins`.
+pub(crate) mod sha256;
+
+// Secret-vs-secret bit comparisons, built the same way as `sha256` above;
+// see `DietMacAndCheeseConvProver::lt` and friends below.
+mod cmp;
+
+// Fixed-width (u32/u64) integer arithmetic (wrapping add, xor/and/or,
+// rotation) over the same `dmc_f2` bit wires as `sha256`/`cmp`; see
+// `DietMacAndCheeseConvProver::add_uint` and friends below.
+mod uint;
+
+// The secp256k1/ECDSA point-arithmetic gadgets, generic over `BackendT`, used
+// by `EvaluatorCirc::eval_ecdsa_verify` to drive the `Secp256k1`/`Secp256k1order`
+// backends in lockstep. See `crate::plugins::ecdsa::EcdsaVerifyV0`.
+pub(crate) mod ecdsa;
+
 // This file implements IR0+ support for diet-mac-n-cheese and is broken up into the following components:
 //
 // 0)   Assuming `DietMacAndCheeseProver/Verifier` and `BackendT` which provides the interface and implementation of
@@ -111,9 +134,18 @@ where
 pub trait BackendRamT: BackendT {
     fn finalize_ram(&mut self) -> Result<()>;
 
-    fn ram_read(&mut self, addr: &Self::Wire) -> Result<Self::Wire>;
+    /// `addr` and the returned value are each as wide as the `galois_ram_v0`
+    /// instance's configured `SIZE_ADDR`/`value_width` (see
+    /// `plugins/ram.rs::RamV0`); `value_width` is passed explicitly since,
+    /// on the very first access, nothing else pins down how wide a value
+    /// this memory stores.
+    fn ram_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>>;
+
+    fn ram_write(&mut self, addr: &[Self::Wire], new: &[Self::Wire]) -> Result<()>;
 
-    fn ram_write(&mut self, addr: &Self::Wire, new: &Self::Wire) -> Result<()>;
+    /// Read from the preinitialized, write-free memory backing a
+    /// `"read_only"` `galois_ram_v0` instance (see `ram::RomProver`).
+    fn rom_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>>;
 }
 
 impl<V: IsSubFieldOf<F40b>, C: AbstractChannel> BackendRamT for DietMacAndCheeseProver<V, F40b, C>
@@ -124,11 +156,15 @@ where
         Ok(())
     }
 
-    fn ram_read(&mut self, addr: &Self::Wire) -> Result<Self::Wire> {
+    fn ram_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
         unimplemented!()
     }
 
-    fn ram_write(&mut self, addr: &Self::Wire, val: &Self::Wire) -> Result<()> {
+    fn ram_write(&mut self, addr: &[Self::Wire], val: &[Self::Wire]) -> Result<()> {
+        unimplemented!()
+    }
+
+    fn rom_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
         unimplemented!()
     }
 }
@@ -141,11 +177,114 @@ where
         Ok(())
     }
 
-    fn ram_read(&mut self, addr: &Self::Wire) -> Result<Self::Wire> {
+    fn ram_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        unimplemented!()
+    }
+
+    fn ram_write(&mut self, addr: &[Self::Wire], val: &[Self::Wire]) -> Result<()> {
         unimplemented!()
     }
 
-    fn ram_write(&mut self, addr: &Self::Wire, val: &Self::Wire) -> Result<()> {
+    fn rom_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        unimplemented!()
+    }
+}
+
+/// Prove that queried wires lie in a committed/public table via the
+/// log-derivative (logUp) identity, as an alternative to spending one Dora
+/// disjunction per branch. A table is registered once under an `id` with
+/// [`Self::table_init`]; every subsequent [`Self::lookup`] against that
+/// `id` accumulates the query, and [`Self::finalize_lookup`] closes out
+/// every table's argument in one pass, analogous to how `edabits_map`
+/// batches conversions until `finalize_conv`.
+pub trait BackendLookupT: BackendT {
+    // finalize every pending lookup argument
+    fn finalize_lookup(&mut self) -> Result<()>;
+
+    // register a table under `id`, to be referenced by later `lookup` calls
+    fn table_init(&mut self, id: usize, table: Vec<Self::FieldElement>) -> Result<()>;
+
+    // assert that `val` is a member of the table registered under `id`,
+    // returning it unchanged so the call can be chained into a larger
+    // expression
+    fn lookup(&mut self, id: usize, val: &Self::Wire) -> Result<Self::Wire>;
+}
+
+impl<V: IsSubFieldOf<F40b>, C: AbstractChannel> BackendLookupT for DietMacAndCheeseProver<V, F40b, C>
+where
+    <F40b as FiniteField>::PrimeField: IsSubFieldOf<V>,
+{
+    fn finalize_lookup(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn table_init(&mut self, _id: usize, _table: Vec<Self::FieldElement>) -> Result<()> {
+        unimplemented!()
+    }
+
+    fn lookup(&mut self, _id: usize, _val: &Self::Wire) -> Result<Self::Wire> {
+        unimplemented!()
+    }
+}
+
+impl<V: IsSubFieldOf<F40b>, C: AbstractChannel> BackendLookupT
+    for DietMacAndCheeseVerifier<V, F40b, C>
+where
+    <F40b as FiniteField>::PrimeField: IsSubFieldOf<V>,
+{
+    fn finalize_lookup(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn table_init(&mut self, _id: usize, _table: Vec<Self::FieldElement>) -> Result<()> {
+        unimplemented!()
+    }
+
+    fn lookup(&mut self, _id: usize, _val: &Self::Wire) -> Result<Self::Wire> {
+        unimplemented!()
+    }
+}
+
+/// A decomposable (Lasso-style) lookup: `galois_lookup_v0` splits a wide
+/// value into narrow digits and checks each digit's membership in its own
+/// small table via the `ram` module's permutation check, rather than
+/// `BackendLookupT`'s logUp identity over one big table (see
+/// `plugins/galois_lookup.rs`).
+pub trait BackendGaloisLookupT: BackendT {
+    fn galois_lookup(
+        &mut self,
+        digits: &[Self::Wire],
+        width: usize,
+        base: Self::FieldElement,
+    ) -> Result<Self::Wire>;
+}
+
+impl<V: IsSubFieldOf<F40b>, C: AbstractChannel> BackendGaloisLookupT
+    for DietMacAndCheeseProver<V, F40b, C>
+where
+    <F40b as FiniteField>::PrimeField: IsSubFieldOf<V>,
+{
+    fn galois_lookup(
+        &mut self,
+        _digits: &[Self::Wire],
+        _width: usize,
+        _base: Self::FieldElement,
+    ) -> Result<Self::Wire> {
+        unimplemented!()
+    }
+}
+
+impl<V: IsSubFieldOf<F40b>, C: AbstractChannel> BackendGaloisLookupT
+    for DietMacAndCheeseVerifier<V, F40b, C>
+where
+    <F40b as FiniteField>::PrimeField: IsSubFieldOf<V>,
+{
+    fn galois_lookup(
+        &mut self,
+        _digits: &[Self::Wire],
+        _width: usize,
+        _base: Self::FieldElement,
+    ) -> Result<Self::Wire> {
         unimplemented!()
     }
 }
@@ -232,12 +371,23 @@ impl<E> EdabitsMap<E> {
     }
 }
 
+/// Accumulated state for one `BackendLookupT` table: the (public) table
+/// values, and every query wire committed against it since the table was
+/// registered. `W` is the wire type (`MacProver`/`MacVerifier`), so the
+/// same definition serves both the prover and the verifier side.
+struct LookupState<F: FiniteField, W> {
+    table: Vec<F>,
+    queries: Vec<W>,
+}
+
 struct DietMacAndCheeseConvProver<FE: FiniteField, C: AbstractChannel> {
     dmc: DietMacAndCheeseProver<FE, FE, C>,
     ram: ram::MemoryProver<FE, FE, C>,
+    rom: ram::RomMemoryProver<FE, FE, C>,
     conv: ProverConv<FE>,
     dora: HashMap<usize, DoraState<FE, FE, C>>,
     edabits_map: EdabitsMap<EdabitsProver<FE>>,
+    lookup: HashMap<usize, LookupState<FE, MacProver<FE, FE>>>,
     dmc_f2: DietMacAndCheeseProver<F2, F40b, C>,
     no_batching: bool,
 }
@@ -264,8 +414,10 @@ impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvProver<FE, C>
             dmc,
             conv,
             ram: Default::default(),
+            rom: Default::default(),
             dora: Default::default(),
             edabits_map: EdabitsMap::new(),
+            lookup: Default::default(),
             dmc_f2: DietMacAndCheeseProver::<F2, F40b, C>::init_with_fcom(
                 channel,
                 rng2,
@@ -422,16 +574,121 @@ where
 
 impl<FP: PrimeFiniteField, C: AbstractChannel> BackendRamT for DietMacAndCheeseConvProver<FP, C> {
     fn finalize_ram(&mut self) -> Result<()> {
-        self.ram.finalize(&mut self.dmc)
+        self.ram.finalize(&mut self.dmc)?;
+        self.rom.finalize(&mut self.dmc)
     }
 
-    fn ram_read(&mut self, addr: &Self::Wire) -> Result<Self::Wire> {
-        self.ram.read(&mut self.dmc, addr)
+    fn ram_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        self.ram.read(&mut self.dmc, addr, value_width)
     }
 
-    fn ram_write(&mut self, addr: &Self::Wire, value: &Self::Wire) -> Result<()> {
+    fn ram_write(&mut self, addr: &[Self::Wire], value: &[Self::Wire]) -> Result<()> {
         self.ram.write(&mut self.dmc, addr, value)
     }
+
+    fn rom_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        self.rom.read(&mut self.dmc, addr, value_width)
+    }
+}
+
+impl<FP: PrimeFiniteField, C: AbstractChannel> BackendGaloisLookupT for DietMacAndCheeseConvProver<FP, C> {
+    fn galois_lookup(&mut self, digits: &[Self::Wire], width: usize, base: FP) -> Result<Self::Wire> {
+        let size = 1usize << width;
+        let mut output = self.dmc.input_public(FP::ZERO)?;
+        let mut weight = FP::ONE;
+        for digit in digits {
+            let mut table =
+                ram::Prover::<FP, FP, C, ram::Bounded<FP>, 1, 1, 3, 2, 4>::new(&mut self.dmc, ram::Bounded::new(size));
+
+            let mut addr = FP::ZERO;
+            for _ in 0..size {
+                let addr_mac = self.dmc.input_public(addr)?;
+                let value_mac = self.dmc.input_public(addr)?;
+                table.insert(&mut self.dmc, &[addr_mac], &[value_mac])?;
+                addr += FP::ONE;
+            }
+            let value = table.remove(&mut self.dmc, &[*digit])?[0];
+            table.finalize(&mut self.dmc)?;
+
+            let term = self.dmc.mul_constant(&value, weight)?;
+            output = self.dmc.add(&output, &term)?;
+            weight *= base;
+        }
+        Ok(output)
+    }
+}
+
+impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvProver<FE, C> {
+    /// Commit a fresh wire `w = 1/(α − x)`, enforced by `w·(α − x) = 1`.
+    fn commit_reciprocal(&mut self, x: &MacProver<FE, FE>, alpha: FE) -> Result<MacProver<FE, FE>> {
+        let neg_x = self.dmc.mul_constant(x, -FE::ONE)?;
+        let diff = self.dmc.add_constant(&neg_x, alpha)?;
+        let w = self.dmc.input_private(Some((alpha - x.value()).inverse()))?;
+        let check = self.dmc.mul(&w, &diff)?;
+        let hope_one = self.dmc.add_constant(&check, -FE::ONE)?;
+        self.dmc.assert_zero(&hope_one)?;
+        Ok(w)
+    }
+}
+
+impl<FE: PrimeFiniteField, C: AbstractChannel> BackendLookupT for DietMacAndCheeseConvProver<FE, C> {
+    fn table_init(&mut self, id: usize, table: Vec<Self::FieldElement>) -> Result<()> {
+        self.lookup.insert(
+            id,
+            LookupState {
+                table,
+                queries: Vec::new(),
+            },
+        );
+        Ok(())
+    }
+
+    fn lookup(&mut self, id: usize, val: &Self::Wire) -> Result<Self::Wire> {
+        self.lookup
+            .get_mut(&id)
+            .expect("lookup table not initialized")
+            .queries
+            .push(*val);
+        Ok(*val)
+    }
+
+    fn finalize_lookup(&mut self) -> Result<()> {
+        for (_id, state) in std::mem::take(&mut self.lookup) {
+            let alpha = self.dmc.channel.read_serializable::<FE>()?;
+
+            // multiplicities: how many queries equal each table entry
+            let mut mult = vec![FE::ZERO; state.table.len()];
+            for q in &state.queries {
+                let pos = state
+                    .table
+                    .iter()
+                    .position(|t| *t == q.value())
+                    .ok_or_else(|| eyre::eyre!("lookup value is not a member of the table"))?;
+                mult[pos] += FE::ONE;
+            }
+
+            // Σ_k 1/(α − q_k)
+            let mut lhs = self.dmc.input_public(FE::ZERO)?;
+            for q in &state.queries {
+                let w = self.commit_reciprocal(q, alpha)?;
+                lhs = self.dmc.add(&lhs, &w)?;
+            }
+
+            // Σ_j m_j/(α − t_j)
+            let mut rhs = self.dmc.input_public(FE::ZERO)?;
+            for (t, m) in state.table.iter().zip(mult.iter()) {
+                let t_wire = self.dmc.input_public(*t)?;
+                let w = self.commit_reciprocal(&t_wire, alpha)?;
+                let m_wire = self.dmc.input_private(Some(*m))?;
+                let term = self.dmc.mul(&m_wire, &w)?;
+                rhs = self.dmc.add(&rhs, &term)?;
+            }
+
+            let diff = self.dmc.sub(&lhs, &rhs)?;
+            self.dmc.assert_zero(&diff)?;
+        }
+        Ok(())
+    }
 }
 
 // Note: The restriction to a primefield is not caused by Dora
@@ -600,28 +857,412 @@ impl<FE: PrimeFiniteField, C: AbstractChannel> BackendConvT for DietMacAndCheese
     }
 }
 
+impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvProver<FE, C> {
+    /// Promote a [`MacBitGeneric`] to a `dmc_f2`-committed wire, mirroring
+    /// the `BitPublic` handling in `assert_conv_from_bits`.
+    fn bit_to_f2_wire(&mut self, bit: &MacBitGeneric) -> Result<MacProver<F2, F40b>> {
+        match bit {
+            MacBitGeneric::BitProver(m) => Ok(*m),
+            MacBitGeneric::BitVerifier(_) => panic!("Should not be a Verifier value"),
+            MacBitGeneric::BitPublic(b) => {
+                let m = self.dmc_f2.input_private(Some(*b))?;
+                let hope_zero = self.dmc_f2.add_constant(&m, *b)?;
+                self.dmc_f2.assert_zero(&hope_zero)?;
+                Ok(m)
+            }
+        }
+    }
+
+    /// Run the SHA-256 compression function over 512 input bits committed
+    /// on the cheap `dmc_f2` (GF(2)) sub-backend, without round-tripping
+    /// through `FE`. See [`sha256::sha256`] for the gadget itself.
+    pub(crate) fn sha256(&mut self, input: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let wires = input
+            .iter()
+            .map(|b| self.bit_to_f2_wire(b))
+            .collect::<Result<Vec<_>>>()?;
+        let out = sha256::sha256(&mut self.dmc_f2, &wires)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// Run the BLAKE2s compression function over 512 input bits, analogous
+    /// to [`Self::sha256`]. See [`sha256::blake2s`] for the gadget itself.
+    pub(crate) fn blake2s(&mut self, input: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let wires = input
+            .iter()
+            .map(|b| self.bit_to_f2_wire(b))
+            .collect::<Result<Vec<_>>>()?;
+        let out = sha256::blake2s(&mut self.dmc_f2, &wires)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    fn to_f2_wires(&mut self, bits: &[MacBitGeneric]) -> Result<Vec<MacProver<F2, F40b>>> {
+        bits.iter().map(|b| self.bit_to_f2_wire(b)).collect()
+    }
+
+    /// Decompose `a`/`b` to bits via `assert_conv_to_bits` and compute
+    /// `a < b`, returned as a fresh `dmc_f2` bit; the result itself is not
+    /// asserted.
+    pub(crate) fn lt(&mut self, a: &MacProver<FE, FE>, b: &MacProver<FE, FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::less_than(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitProver(r))
+    }
+
+    /// As [`Self::lt`], for `a <= b`.
+    pub(crate) fn le(&mut self, a: &MacProver<FE, FE>, b: &MacProver<FE, FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::less_eq(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitProver(r))
+    }
+
+    /// As [`Self::lt`], for `a == b`.
+    pub(crate) fn eq(&mut self, a: &MacProver<FE, FE>, b: &MacProver<FE, FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::equal(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitProver(r))
+    }
+
+    /// Assert `0 <= val < 2^k` by decomposing `val` via `assert_conv_to_bits`
+    /// and asserting every bit at position `k` and above is zero.
+    pub(crate) fn range_check(&mut self, val: &MacProver<FE, FE>, k: usize) -> Result<()> {
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(k) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+        Ok(())
+    }
+
+    /// `a ^ b` for equal-length bit words; see [`uint::xor_word`].
+    pub(crate) fn xor_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::xor_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// `a & b` for equal-length bit words; see [`uint::and_word`].
+    pub(crate) fn and_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::and_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// `a | b` for equal-length bit words; see [`uint::or_word`].
+    pub(crate) fn or_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::or_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// Wrapping `a + b` for equal-length bit words; see [`uint::add_mod`].
+    pub(crate) fn add_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::add_mod(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// Rotate-left by `n`; see [`uint::rotl`].
+    pub(crate) fn rotl_uint(&mut self, a: &[MacBitGeneric], n: usize) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let out = uint::rotl(&mut self.dmc_f2, &a, n)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// Rotate-right by `n`; see [`uint::rotr`].
+    pub(crate) fn rotr_uint(&mut self, a: &[MacBitGeneric], n: usize) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let out = uint::rotr(&mut self.dmc_f2, &a, n)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitProver).collect())
+    }
+
+    /// Unpack `val` into a `width`-bit little-endian word via
+    /// `assert_conv_to_bits`, asserting every bit at position `width` and
+    /// above is zero (same check as [`Self::range_check`]).
+    pub(crate) fn unpack_uint(&mut self, val: &MacProver<FE, FE>, width: usize) -> Result<Vec<MacBitGeneric>> {
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(width) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+        Ok(bits.into_iter().take(width).collect())
+    }
+
+    /// Pack a little-endian bit word back into a single `FE` wire via
+    /// `assert_conv_from_bits`, the inverse of [`Self::unpack_uint`].
+    pub(crate) fn pack_uint(&mut self, word: &[MacBitGeneric]) -> Result<MacProver<FE, FE>> {
+        self.assert_conv_from_bits(word)
+    }
+
+    /// Assert `0 <= val < 2^n` in `O(n/b)` lookups instead of [`Self::range_check`]'s
+    /// `O(n)` bit constraints: decompose `val` into `k = ceil(n/b)` limbs of
+    /// `b` bits via `assert_conv_to_bits`/`pack_uint` (the last limb shorter
+    /// if `b` doesn't divide `n`), range-check each limb with one
+    /// [`Self::lookup_batch`] call against the precomputed table
+    /// `{0,...,2^b-1}` (a separate, smaller table for the short final limb),
+    /// and tie the limbs back to `val` with one linear reconstruction
+    /// `val = Σ limb_j * 2^(b*j)`.
+    ///
+    /// Pick `b` (e.g. 8 or 16) to trade lookup-table size against lookup
+    /// count: this matters most for the secp256k1/384-bit fields used
+    /// elsewhere in this module, where a per-bit proof is `O(n)` multiplication
+    /// gates but this is `O(n/b)` lookups plus one reconstruction check.
+    pub(crate) fn range_check_lookup(&mut self, val: &MacProver<FE, FE>, n: usize, b: usize) -> Result<()> {
+        assert!(b >= 1, "limb width must be positive");
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(n) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+
+        let two = FE::ONE + FE::ONE;
+        let mut weight = FE::ONE;
+        let mut full_limbs = Vec::new();
+        let mut short_limb = None;
+        let mut reconstructed = self.dmc.input_public(FE::ZERO)?;
+        for chunk in bits[..n].chunks(b) {
+            let limb = self.pack_uint(chunk)?;
+            let term = self.dmc.mul_constant(&limb, weight)?;
+            reconstructed = self.dmc.add(&reconstructed, &term)?;
+            if chunk.len() == b {
+                full_limbs.push(limb);
+            } else {
+                short_limb = Some((limb, chunk.len()));
+            }
+            for _ in 0..chunk.len() {
+                weight *= two;
+            }
+        }
+
+        let diff = self.dmc.sub(val, &reconstructed)?;
+        self.dmc.assert_zero(&diff)?;
+
+        if !full_limbs.is_empty() {
+            let table = (0..(1u64 << b))
+                .map(|i| FE::try_from_int(i).unwrap())
+                .collect::<Vec<_>>();
+            self.lookup_batch(&table, &full_limbs)?;
+        }
+        if let Some((limb, width)) = short_limb {
+            let table = (0..(1u64 << width))
+                .map(|i| FE::try_from_int(i).unwrap())
+                .collect::<Vec<_>>();
+            self.lookup_batch(&table, &[limb])?;
+        }
+        Ok(())
+    }
+
+    /// Batched logUp lookup: proves every element of `values` occurs in the
+    /// public `table`, in one call over an already-fully-known column.
+    ///
+    /// This is the same rational identity as `BackendLookupT::finalize_lookup`
+    /// above (`Σ 1/(α−f_i) = Σ m_j/(α−t_j)`, via `commit_reciprocal`), but a
+    /// different entry point: `table_init`/`lookup`/`finalize_lookup`
+    /// accumulate queries across the whole circuit, keyed by table `id`,
+    /// only closing the argument out at `finalize_lookup` time. `lookup_batch`
+    /// is for a caller (e.g. `range_check_lookup` above) that already has the
+    /// whole column in hand and wants to discharge the argument immediately,
+    /// with no deferred finalization step. The SIEVE
+    /// `@plugin(lookup_v0, ...)` call gate (`PluginExecution::Lookup`, see
+    /// `src/plugins/lookup.rs`) is a third, separate logUp entry point:
+    /// `LookupV0::execute`, generic over any `BackendT`, with the
+    /// multiplicities supplied by the circuit.
+    ///
+    /// Unlike `finalize_lookup`, this re-samples the Fiat-Shamir challenge
+    /// `α` on a denominator collision (`α == x` for some table entry or
+    /// queried value, which would make `commit_reciprocal`'s `1/(α − x)`
+    /// undefined) and checks that the committed multiplicities sum to
+    /// `values.len()`.
+    pub(crate) fn lookup_batch(
+        &mut self,
+        table: &[FE],
+        values: &[MacProver<FE, FE>],
+    ) -> Result<()> {
+        let alpha = loop {
+            let candidate = self.dmc.channel.read_serializable::<FE>()?;
+            let collides = table.iter().any(|t| *t == candidate)
+                || values.iter().any(|v| v.value() == candidate);
+            self.dmc
+                .channel
+                .write_serializable(&(if collides { FE::ONE } else { FE::ZERO }))?;
+            self.dmc.channel.flush()?;
+            if !collides {
+                break candidate;
+            }
+        };
+
+        // multiplicities: how many values equal each table entry
+        let mut mult = vec![FE::ZERO; table.len()];
+        for v in values {
+            let pos = table
+                .iter()
+                .position(|t| *t == v.value())
+                .ok_or_else(|| eyre::eyre!("lookup_batch: value is not a member of the table"))?;
+            mult[pos] += FE::ONE;
+        }
+
+        // Σ_i 1/(α − f_i)
+        let mut lhs = self.dmc.input_public(FE::ZERO)?;
+        for v in values {
+            let w = self.commit_reciprocal(v, alpha)?;
+            lhs = self.dmc.add(&lhs, &w)?;
+        }
+
+        // Σ_j m_j/(α − t_j), tracking Σ m_j alongside
+        let mut rhs = self.dmc.input_public(FE::ZERO)?;
+        let mut mult_sum = self.dmc.input_public(FE::ZERO)?;
+        for (t, m) in table.iter().zip(mult.iter()) {
+            let t_wire = self.dmc.input_public(*t)?;
+            let w = self.commit_reciprocal(&t_wire, alpha)?;
+            let m_wire = self.dmc.input_private(Some(*m))?;
+            let term = self.dmc.mul(&m_wire, &w)?;
+            rhs = self.dmc.add(&rhs, &term)?;
+            mult_sum = self.dmc.add(&mult_sum, &m_wire)?;
+        }
+
+        let diff = self.dmc.sub(&lhs, &rhs)?;
+        self.dmc.assert_zero(&diff)?;
+
+        let n = FE::try_from_int(values.len() as u64).unwrap();
+        let mult_sum_minus_n = self.dmc.add_constant(&mult_sum, -n)?;
+        self.dmc.assert_zero(&mult_sum_minus_n)
+    }
+}
+
 struct DietMacAndCheeseConvVerifier<FE: FiniteField, C: AbstractChannel> {
     dmc: DietMacAndCheeseVerifier<FE, FE, C>,
     conv: VerifierConv<FE>,
     ram: ram::MemoryVerifier<FE, FE, C>,
+    rom: ram::RomMemoryVerifier<FE, FE, C>,
     dora: HashMap<usize, DoraVerifier<FE, FE, C>>,
     edabits_map: EdabitsMap<EdabitsVerifier<FE>>,
+    lookup: HashMap<usize, LookupState<FE, MacVerifier<FE>>>,
     dmc_f2: DietMacAndCheeseVerifier<F2, F40b, C>,
     no_batching: bool,
 }
 
 impl<FE: PrimeFiniteField, C: AbstractChannel> BackendRamT for DietMacAndCheeseConvVerifier<FE, C> {
     fn finalize_ram(&mut self) -> Result<()> {
-        self.ram.finalize(&mut self.dmc)
+        self.ram.finalize(&mut self.dmc)?;
+        self.rom.finalize(&mut self.dmc)
     }
 
-    fn ram_read(&mut self, addr: &Self::Wire) -> Result<Self::Wire> {
-        self.ram.read(&mut self.dmc, addr)
+    fn ram_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        self.ram.read(&mut self.dmc, addr, value_width)
     }
 
-    fn ram_write(&mut self, addr: &Self::Wire, value: &Self::Wire) -> Result<()> {
+    fn ram_write(&mut self, addr: &[Self::Wire], value: &[Self::Wire]) -> Result<()> {
         self.ram.write(&mut self.dmc, addr, value)
     }
+
+    fn rom_read(&mut self, addr: &[Self::Wire], value_width: usize) -> Result<Vec<Self::Wire>> {
+        self.rom.read(&mut self.dmc, addr, value_width)
+    }
+}
+
+impl<FE: PrimeFiniteField, C: AbstractChannel> BackendGaloisLookupT for DietMacAndCheeseConvVerifier<FE, C> {
+    fn galois_lookup(&mut self, digits: &[Self::Wire], width: usize, base: FE) -> Result<Self::Wire> {
+        let size = 1usize << width;
+        let mut output = self.dmc.input_public(FE::ZERO)?;
+        let mut weight = FE::ONE;
+        for digit in digits {
+            let mut table =
+                ram::Verifier::<FE, FE, C, ram::Bounded<FE>, 1, 1, 3, 2, 4>::new(&mut self.dmc, ram::Bounded::new(size));
+
+            let mut addr = FE::ZERO;
+            for _ in 0..size {
+                let addr_mac = self.dmc.input_public(addr)?;
+                let value_mac = self.dmc.input_public(addr)?;
+                table.insert(&mut self.dmc, &[addr_mac], &[value_mac])?;
+                addr += FE::ONE;
+            }
+            let value = table.remove(&mut self.dmc, &[*digit])?[0];
+            table.finalize(&mut self.dmc)?;
+
+            let term = self.dmc.mul_constant(&value, weight)?;
+            output = self.dmc.add(&output, &term)?;
+            weight *= base;
+        }
+        Ok(output)
+    }
+}
+
+impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvVerifier<FE, C> {
+    /// Verifier-side counterpart of `DietMacAndCheeseConvProver::commit_reciprocal`:
+    /// the same `w·(α − x) = 1` constraint, with `w` an opaque committed wire.
+    fn commit_reciprocal(&mut self, x: &MacVerifier<FE>, alpha: FE) -> Result<MacVerifier<FE>> {
+        let neg_x = self.dmc.mul_constant(x, -FE::ONE)?;
+        let diff = self.dmc.add_constant(&neg_x, alpha)?;
+        let w = self.dmc.input_private(None)?;
+        let check = self.dmc.mul(&w, &diff)?;
+        let hope_one = self.dmc.add_constant(&check, -FE::ONE)?;
+        self.dmc.assert_zero(&hope_one)?;
+        Ok(w)
+    }
+}
+
+impl<FE: PrimeFiniteField, C: AbstractChannel> BackendLookupT for DietMacAndCheeseConvVerifier<FE, C> {
+    fn table_init(&mut self, id: usize, table: Vec<Self::FieldElement>) -> Result<()> {
+        self.lookup.insert(
+            id,
+            LookupState {
+                table,
+                queries: Vec::new(),
+            },
+        );
+        Ok(())
+    }
+
+    fn lookup(&mut self, id: usize, val: &Self::Wire) -> Result<Self::Wire> {
+        self.lookup
+            .get_mut(&id)
+            .expect("lookup table not initialized")
+            .queries
+            .push(*val);
+        Ok(*val)
+    }
+
+    fn finalize_lookup(&mut self) -> Result<()> {
+        for (_id, state) in std::mem::take(&mut self.lookup) {
+            let alpha = FE::random(&mut self.dmc.rng);
+            self.dmc.channel.write_serializable(&alpha)?;
+            self.dmc.channel.flush()?;
+
+            // Σ_k 1/(α − q_k)
+            let mut lhs = self.dmc.input_public(FE::ZERO)?;
+            for q in &state.queries {
+                let w = self.commit_reciprocal(q, alpha)?;
+                lhs = self.dmc.add(&lhs, &w)?;
+            }
+
+            // Σ_j m_j/(α − t_j), with m_j committed by the prover
+            let mut rhs = self.dmc.input_public(FE::ZERO)?;
+            for t in state.table.iter() {
+                let t_wire = self.dmc.input_public(*t)?;
+                let w = self.commit_reciprocal(&t_wire, alpha)?;
+                let m_wire = self.dmc.input_private(None)?;
+                let term = self.dmc.mul(&m_wire, &w)?;
+                rhs = self.dmc.add(&rhs, &term)?;
+            }
+
+            let diff = self.dmc.sub(&lhs, &rhs)?;
+            self.dmc.assert_zero(&diff)?;
+        }
+        Ok(())
+    }
 }
 
 impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvVerifier<FE, C> {
@@ -646,8 +1287,10 @@ impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvVerifier<FE,
             dmc,
             conv,
             ram: Default::default(),
+            rom: Default::default(),
             dora: Default::default(),
             edabits_map: EdabitsMap::new(),
+            lookup: Default::default(),
             dmc_f2: DietMacAndCheeseVerifier::<F2, F40b, C>::init_with_fcom(
                 channel,
                 rng2,
@@ -897,6 +1540,236 @@ impl<FE: PrimeFiniteField, C: AbstractChannel> BackendConvT
     }
 }
 
+impl<FE: PrimeFiniteField, C: AbstractChannel> DietMacAndCheeseConvVerifier<FE, C> {
+    /// Promote a [`MacBitGeneric`] to a `dmc_f2`-committed wire, mirroring
+    /// the `BitPublic` handling in `assert_conv_from_bits`.
+    fn bit_to_f2_wire(&mut self, bit: &MacBitGeneric) -> Result<MacVerifier<F40b>> {
+        match bit {
+            MacBitGeneric::BitVerifier(m) => Ok(*m),
+            MacBitGeneric::BitProver(_) => panic!("Should not be a Prover value"),
+            MacBitGeneric::BitPublic(b) => {
+                let m = self.dmc_f2.input_private(None)?;
+                let hope_zero = self.dmc_f2.add_constant(&m, *b)?;
+                self.dmc_f2.assert_zero(&hope_zero)?;
+                Ok(m)
+            }
+        }
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::sha256`].
+    pub(crate) fn sha256(&mut self, input: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let wires = input
+            .iter()
+            .map(|b| self.bit_to_f2_wire(b))
+            .collect::<Result<Vec<_>>>()?;
+        let out = sha256::sha256(&mut self.dmc_f2, &wires)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::blake2s`].
+    pub(crate) fn blake2s(&mut self, input: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let wires = input
+            .iter()
+            .map(|b| self.bit_to_f2_wire(b))
+            .collect::<Result<Vec<_>>>()?;
+        let out = sha256::blake2s(&mut self.dmc_f2, &wires)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    fn to_f2_wires(&mut self, bits: &[MacBitGeneric]) -> Result<Vec<MacVerifier<F40b>>> {
+        bits.iter().map(|b| self.bit_to_f2_wire(b)).collect()
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::lt`].
+    pub(crate) fn lt(&mut self, a: &MacVerifier<FE>, b: &MacVerifier<FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::less_than(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitVerifier(r))
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::le`].
+    pub(crate) fn le(&mut self, a: &MacVerifier<FE>, b: &MacVerifier<FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::less_eq(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitVerifier(r))
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::eq`].
+    pub(crate) fn eq(&mut self, a: &MacVerifier<FE>, b: &MacVerifier<FE>) -> Result<MacBitGeneric> {
+        let abits = self.assert_conv_to_bits(a)?;
+        let bbits = self.assert_conv_to_bits(b)?;
+        let abits = self.to_f2_wires(&abits)?;
+        let bbits = self.to_f2_wires(&bbits)?;
+        let r = cmp::equal(&mut self.dmc_f2, &abits, &bbits)?;
+        Ok(MacBitGeneric::BitVerifier(r))
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::range_check`].
+    pub(crate) fn range_check(&mut self, val: &MacVerifier<FE>, k: usize) -> Result<()> {
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(k) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+        Ok(())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::xor_uint`].
+    pub(crate) fn xor_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::xor_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::and_uint`].
+    pub(crate) fn and_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::and_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::or_uint`].
+    pub(crate) fn or_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::or_word(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::add_uint`].
+    pub(crate) fn add_uint(&mut self, a: &[MacBitGeneric], b: &[MacBitGeneric]) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let b = self.to_f2_wires(b)?;
+        let out = uint::add_mod(&mut self.dmc_f2, &a, &b)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::rotl_uint`].
+    pub(crate) fn rotl_uint(&mut self, a: &[MacBitGeneric], n: usize) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let out = uint::rotl(&mut self.dmc_f2, &a, n)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::rotr_uint`].
+    pub(crate) fn rotr_uint(&mut self, a: &[MacBitGeneric], n: usize) -> Result<Vec<MacBitGeneric>> {
+        let a = self.to_f2_wires(a)?;
+        let out = uint::rotr(&mut self.dmc_f2, &a, n)?;
+        Ok(out.into_iter().map(MacBitGeneric::BitVerifier).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::unpack_uint`].
+    pub(crate) fn unpack_uint(&mut self, val: &MacVerifier<FE>, width: usize) -> Result<Vec<MacBitGeneric>> {
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(width) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+        Ok(bits.into_iter().take(width).collect())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::pack_uint`].
+    pub(crate) fn pack_uint(&mut self, word: &[MacBitGeneric]) -> Result<MacVerifier<FE>> {
+        self.assert_conv_from_bits(word)
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::range_check_lookup`].
+    pub(crate) fn range_check_lookup(&mut self, val: &MacVerifier<FE>, n: usize, b: usize) -> Result<()> {
+        assert!(b >= 1, "limb width must be positive");
+        let bits = self.assert_conv_to_bits(val)?;
+        for bit in bits.iter().skip(n) {
+            let w = self.bit_to_f2_wire(bit)?;
+            self.dmc_f2.assert_zero(&w)?;
+        }
+
+        let two = FE::ONE + FE::ONE;
+        let mut weight = FE::ONE;
+        let mut full_limbs = Vec::new();
+        let mut short_limb = None;
+        let mut reconstructed = self.dmc.input_public(FE::ZERO)?;
+        for chunk in bits[..n].chunks(b) {
+            let limb = self.pack_uint(chunk)?;
+            let term = self.dmc.mul_constant(&limb, weight)?;
+            reconstructed = self.dmc.add(&reconstructed, &term)?;
+            if chunk.len() == b {
+                full_limbs.push(limb);
+            } else {
+                short_limb = Some((limb, chunk.len()));
+            }
+            for _ in 0..chunk.len() {
+                weight *= two;
+            }
+        }
+
+        let diff = self.dmc.sub(val, &reconstructed)?;
+        self.dmc.assert_zero(&diff)?;
+
+        if !full_limbs.is_empty() {
+            let table = (0..(1u64 << b))
+                .map(|i| FE::try_from_int(i).unwrap())
+                .collect::<Vec<_>>();
+            self.lookup_batch(&table, &full_limbs)?;
+        }
+        if let Some((limb, width)) = short_limb {
+            let table = (0..(1u64 << width))
+                .map(|i| FE::try_from_int(i).unwrap())
+                .collect::<Vec<_>>();
+            self.lookup_batch(&table, &[limb])?;
+        }
+        Ok(())
+    }
+
+    /// Verifier-side counterpart of [`DietMacAndCheeseConvProver::lookup_batch`]:
+    /// samples `α`, re-sampling whenever the prover reports a denominator
+    /// collision against the (here, public) `table`, then runs the same
+    /// `commit_reciprocal`-based logUp identity and multiplicity-sum check
+    /// with opaque (unknown-value) wires. See the prover method for how this
+    /// differs from `finalize_lookup` and from `PluginExecution::Lookup`.
+    pub(crate) fn lookup_batch(&mut self, table: &[FE], values: &[MacVerifier<FE>]) -> Result<()> {
+        let alpha = loop {
+            let candidate = FE::random(&mut self.dmc.rng);
+            self.dmc.channel.write_serializable(&candidate)?;
+            self.dmc.channel.flush()?;
+            let retry = self.dmc.channel.read_serializable::<FE>()?;
+            if retry == FE::ZERO {
+                break candidate;
+            }
+        };
+
+        let mut lhs = self.dmc.input_public(FE::ZERO)?;
+        for v in values {
+            let w = self.commit_reciprocal(v, alpha)?;
+            lhs = self.dmc.add(&lhs, &w)?;
+        }
+
+        let mut rhs = self.dmc.input_public(FE::ZERO)?;
+        let mut mult_sum = self.dmc.input_public(FE::ZERO)?;
+        for t in table.iter() {
+            let t_wire = self.dmc.input_public(*t)?;
+            let w = self.commit_reciprocal(&t_wire, alpha)?;
+            let m_wire = self.dmc.input_private(None)?;
+            let term = self.dmc.mul(&m_wire, &w)?;
+            rhs = self.dmc.add(&rhs, &term)?;
+            mult_sum = self.dmc.add(&mult_sum, &m_wire)?;
+        }
+
+        let diff = self.dmc.sub(&lhs, &rhs)?;
+        self.dmc.assert_zero(&diff)?;
+
+        let n = FE::try_from_int(values.len() as u64).unwrap();
+        let mult_sum_minus_n = self.dmc.add_constant(&mult_sum, -n)?;
+        self.dmc.assert_zero(&mult_sum_minus_n)
+    }
+}
+
 // II) Instance/Witness/Relation/Gates/FunStore
 // See circuit_ir.rs
 
@@ -941,6 +1814,12 @@ trait EvaluatorT {
     );
 
     fn finalize(&mut self) -> Result<()>;
+
+    /// Recover the concrete `EvaluatorSingle<B>` behind this trait object.
+    /// Used by `EvaluatorCirc::eval_ecdsa_verify`, which is the one plugin
+    /// that needs two field backends at once and so can't go through the
+    /// single-backend `plugin_call_gate`.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
 }
 
 /// A circuit evaluator for a single [`BackendT`].
@@ -967,7 +1846,8 @@ where
     }
 }
 
-impl<B: BackendConvT + BackendDisjunctionT + BackendRamT> EvaluatorT for EvaluatorSingle<B>
+impl<B: BackendConvT + BackendDisjunctionT + BackendRamT + BackendGaloisLookupT> EvaluatorT
+    for EvaluatorSingle<B>
 where
     B::Wire: Default + Clone + Copy + Debug,
 {
@@ -1093,6 +1973,39 @@ where
                 let ys: Vec<_> = copy_mem(&self.memory, inputs[1]).copied().collect();
                 plugin.execute::<B>(&xs, &ys, &mut self.backend)?
             }
+            PluginExecution::Lookup(plugin) => {
+                assert_eq!(outputs.len(), 0);
+                assert_eq!(inputs.len(), 3);
+                let lookups: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                let table: Vec<_> = copy_mem(&self.memory, inputs[1]).copied().collect();
+                let mult: Vec<_> = copy_mem(&self.memory, inputs[2]).copied().collect();
+                plugin.execute::<B>(&lookups, &table, &mult, &mut self.backend)?
+            }
+            PluginExecution::LessEqThan(plugin) => {
+                ensure!(
+                    self.is_boolean,
+                    "comparison plugin is only supported over the boolean backend"
+                );
+                assert_eq!(outputs.len(), 0);
+                assert_eq!(inputs.len(), 2);
+                let a: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                let b: Vec<_> = copy_mem(&self.memory, inputs[1]).copied().collect();
+                plugin.execute::<B>(&a, &b, &mut self.backend)?
+            }
+            PluginExecution::Sha256(plugin) => {
+                ensure!(self.is_boolean, "Sha256 plugin is only supported over the boolean backend");
+                assert_eq!(outputs.len(), 1);
+                assert_eq!(inputs.len(), 2);
+                let message: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                let state: Vec<_> = copy_mem(&self.memory, inputs[1]).copied().collect();
+                let digest = plugin.execute::<B>(&message, &state, &mut self.backend)?;
+
+                let (w0, w1) = outputs[0];
+                assert_eq!((w1 - w0 + 1) as usize, digest.len());
+                for (w, bit) in (w0..=w1).zip(digest) {
+                    self.memory.set(w, &bit);
+                }
+            }
             PluginExecution::Disjunction(disj) => {
                 assert!(inputs.len() >= 1, "must provide condition");
 
@@ -1131,34 +2044,56 @@ where
                     assert_eq!(inputs.len(), 1);
                     assert_eq!(outputs.len(), 1);
 
-                    // retrieve memory at address
-                    let value = {
-                        let mut addr = copy_mem(&self.memory, inputs[0]);
-                        let addr = addr.next().unwrap();
-                        self.backend.ram_read(addr)?
-                    };
+                    // retrieve memory at address (addr_width wires wide)
+                    let addr: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                    let value = self.backend.ram_read(&addr, plugin.value_width())?;
 
-                    // write to output
+                    // write back to output (value_width wires wide)
                     let (w0, w1) = outputs[0];
-                    assert_eq!(w0, w1);
-                    self.memory.set(w0, &value);
+                    assert_eq!((w1 - w0 + 1) as usize, plugin.value_width());
+                    for (w, v) in (w0..=w1).zip(value) {
+                        self.memory.set(w, &v);
+                    }
                 }
                 RamOperation::Write => {
                     assert_eq!(inputs.len(), 2);
                     assert_eq!(outputs.len(), 0);
 
-                    // retrieve address
-                    let mut addr = copy_mem(&self.memory, inputs[0]);
-                    let addr = addr.next().unwrap();
-
-                    // retrieve value
-                    let mut value = copy_mem(&self.memory, inputs[1]);
-                    let value = value.next().unwrap();
+                    // retrieve address and value
+                    let addr: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                    let value: Vec<_> = copy_mem(&self.memory, inputs[1]).copied().collect();
 
                     // write back to memory
-                    self.backend.ram_write(addr, value)?;
+                    self.backend.ram_write(&addr, &value)?;
+                }
+                RamOperation::ReadOnly => {
+                    assert_eq!(inputs.len(), 1);
+                    assert_eq!(outputs.len(), 1);
+
+                    // retrieve ROM at address (no write path exists for this operation)
+                    let addr: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                    let value = self.backend.rom_read(&addr, plugin.value_width())?;
+
+                    // write back to output (value_width wires wide)
+                    let (w0, w1) = outputs[0];
+                    assert_eq!((w1 - w0 + 1) as usize, plugin.value_width());
+                    for (w, v) in (w0..=w1).zip(value) {
+                        self.memory.set(w, &v);
+                    }
                 }
             },
+            PluginExecution::GaloisLookup(plugin) => {
+                assert_eq!(inputs.len(), 1);
+                assert_eq!(outputs.len(), 1);
+
+                let digits: Vec<_> = copy_mem(&self.memory, inputs[0]).copied().collect();
+                let base = B::from_number(plugin.base())?;
+                let value = self.backend.galois_lookup(&digits, plugin.width(), base)?;
+
+                let (w0, w1) = outputs[0];
+                assert_eq!(w0, w1);
+                self.memory.set(w0, &value);
+            }
             _ => bail!("Plugin {plugin:?} is unsupported"),
         };
         Ok(())
@@ -1260,6 +2195,10 @@ where
         self.backend.finalize()?;
         Ok(())
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 // V) Evaluator for multiple fields
@@ -1274,6 +2213,11 @@ pub struct EvaluatorCirc<C: AbstractChannel + 'static> {
     party: Party,
     rng: AesRng,
     no_batching: bool,
+    /// When set, [`Self::evaluate_gates`] runs [`circuit_ir::validate`] over
+    /// the gate stream first, surfacing `WireRange`/`Conv`/`Call` problems as
+    /// a single aggregated error instead of a panic deep inside a backend.
+    /// See [`Self::set_validate_before_eval`].
+    validate_before_eval: bool,
     phantom: PhantomData<C>,
 }
 
@@ -1323,6 +2267,7 @@ impl<C: AbstractChannel + 'static> EvaluatorCirc<C> {
             f2_idx: 42,
             rng,
             no_batching,
+            validate_before_eval: false,
             phantom: PhantomData,
         })
     }
@@ -1552,7 +2497,21 @@ impl<C: AbstractChannel + 'static> EvaluatorCirc<C> {
         Ok(())
     }
 
+    /// Enable (or disable) running [`circuit_ir::validate`] automatically
+    /// before [`Self::evaluate_gates`], catching bad `WireRange`/`Conv`/
+    /// `Call` usage up front instead of deep inside a backend.
+    pub fn set_validate_before_eval(&mut self, validate: bool) {
+        self.validate_before_eval = validate;
+    }
+
     pub fn evaluate_gates(&mut self, gates: &[GateM], fun_store: &FunStore) -> Result<()> {
+        if self.validate_before_eval {
+            let errors = circuit_ir::validate(gates, &self.type_store, fun_store);
+            if !errors.is_empty() {
+                let messages: Vec<String> = errors.iter().map(ValidationError::to_string).collect();
+                bail!("circuit validation failed:\n{}", messages.join("\n"));
+            }
+        }
         self.evaluate_gates_passed(gates, fun_store)?;
         self.finish()
     }
@@ -1594,6 +2553,59 @@ impl<C: AbstractChannel + 'static> EvaluatorCirc<C> {
         self.finish()
     }
 
+    /// Like [`Self::evaluate_relation`], but overlaps parsing of batch `N+1`
+    /// with evaluation of batch `N`: a producer thread owns the
+    /// `BufRelation`/`RelationReader` and pushes each parsed
+    /// `(Vec<GateM>, FunStore)` batch into a bounded channel of capacity
+    /// `depth`, while this thread pulls batches and evaluates them in the
+    /// order they were parsed. The channel is single-producer/
+    /// single-consumer and FIFO, so gate ordering across batches is exactly
+    /// the producer's read order — preserving the stateful wire allocation
+    /// and `callframe_start`/`callframe_end` bookkeeping
+    /// `evaluate_gates_passed` relies on. `finish()` remains the
+    /// join/finalize barrier: it only runs once every batch has been
+    /// evaluated and the producer thread has exited.
+    pub fn evaluate_relation_pipelined(&mut self, path: &PathBuf, depth: usize) -> Result<()> {
+        let (sender, receiver) = mpsc::sync_channel::<Result<(Vec<GateM>, FunStore)>>(depth);
+        let path = path.clone();
+        let type_store = self.type_store.clone();
+
+        let producer = thread::spawn(move || {
+            let mut buf_rel = match BufRelation::new(&path, &type_store) {
+                Ok(buf_rel) => buf_rel,
+                Err(e) => {
+                    let _ = sender.send(Err(e));
+                    return;
+                }
+            };
+            loop {
+                match buf_rel.next() {
+                    None => break,
+                    Some(()) => {
+                        let batch = (buf_rel.gates.clone(), buf_rel.fun_store.clone());
+                        if sender.send(Ok(batch)).is_err() {
+                            // The consumer stopped early (an earlier batch
+                            // errored out); no point parsing further.
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut result = Ok(());
+        for batch in receiver {
+            result = batch.and_then(|(gates, fun_store)| self.evaluate_gates_passed(&gates, &fun_store));
+            if result.is_err() {
+                break;
+            }
+        }
+
+        producer.join().expect("relation-parsing thread panicked");
+        result?;
+        self.finish()
+    }
+
     pub fn evaluate_relation_text<T: Read + Seek>(&mut self, rel: T) -> Result<()> {
         let rel = RelationReader::new(rel)?;
 
@@ -1660,6 +2672,145 @@ impl<C: AbstractChannel + 'static> EvaluatorCirc<C> {
         }
     }
 
+    /// Evaluate an `EcdsaVerify` plugin call: drives the `Secp256k1` and
+    /// `Secp256k1order` backends together to check a signature, per
+    /// `plugins::ecdsa::EcdsaVerifyV0`. Dispatches to the concrete backend
+    /// types for `self.party` and hands off to [`Self::eval_ecdsa_verify_concrete`].
+    fn eval_ecdsa_verify(&mut self, plugin: &EcdsaVerifyV0, in_ranges: &[WireRange]) -> Result<()> {
+        match self.party {
+            Party::Prover => self.eval_ecdsa_verify_concrete::<
+                DietMacAndCheeseConvProver<Secp256k1, C>,
+                DietMacAndCheeseConvProver<Secp256k1order, C>,
+            >(plugin, in_ranges),
+            Party::Verifier => self.eval_ecdsa_verify_concrete::<
+                DietMacAndCheeseConvVerifier<Secp256k1, C>,
+                DietMacAndCheeseConvVerifier<Secp256k1order, C>,
+            >(plugin, in_ranges),
+        }
+    }
+
+    /// The actual ECDSA verification, generic over the two concrete backend
+    /// types (resolved to a matching prover/verifier pair by
+    /// [`Self::eval_ecdsa_verify`]). This is the one plugin that needs to
+    /// reach behind two different entries of `self.eval` at once: it
+    /// recovers each concrete `EvaluatorSingle` via `EvaluatorT::as_any_mut`,
+    /// reads `Qx, Qy, e, r, s` straight out of their `Memory` (no callframe,
+    /// as there are no outputs to allocate), and then drives the gadgets in
+    /// `backend_multifield::ecdsa` by hand.
+    fn eval_ecdsa_verify_concrete<Bp, Bn>(
+        &mut self,
+        plugin: &EcdsaVerifyV0,
+        in_ranges: &[WireRange],
+    ) -> Result<()>
+    where
+        Bp: BackendConvT<FieldElement = Secp256k1> + 'static,
+        Bp::Wire: Default + Clone + Copy + Debug,
+        Bn: BackendConvT + 'static,
+        Bn::Wire: Default + Clone + Copy + Debug,
+    {
+        use ecdsa::{assert_lt_public, compute_scalars, ec_add, fixed_base_mult, lift_bit};
+        use ecdsa::{order_as_base_field, order_bits_msb_first, scalar_bits_in_other_field};
+        use ecdsa::{assert_on_curve, variable_base_mult, Point};
+
+        ensure!(in_ranges.len() == 2, "ecdsa_verify_v0 expects 2 input ranges");
+        let fp_idx = plugin.fp_field() as usize;
+        let fn_idx = plugin.fn_field() as usize;
+        ensure!(
+            fp_idx != fn_idx,
+            "ecdsa_verify_v0's base and scalar fields must be distinct"
+        );
+
+        let (fp_eval, fn_eval) = if fp_idx < fn_idx {
+            let (left, right) = self.eval.split_at_mut(fn_idx);
+            (&mut left[fp_idx], &mut right[0])
+        } else {
+            let (left, right) = self.eval.split_at_mut(fp_idx);
+            (&mut right[0], &mut left[fn_idx])
+        };
+        let fp_eval = fp_eval
+            .as_any_mut()
+            .downcast_mut::<EvaluatorSingle<Bp>>()
+            .ok_or_else(|| eyre::eyre!("ecdsa_verify_v0: unexpected backend for the base field"))?;
+        let fn_eval = fn_eval
+            .as_any_mut()
+            .downcast_mut::<EvaluatorSingle<Bn>>()
+            .ok_or_else(|| eyre::eyre!("ecdsa_verify_v0: unexpected backend for the scalar field"))?;
+
+        let (qx_start, qx_end) = in_ranges[0];
+        ensure!(qx_end - qx_start + 1 == 2, "expected 2 base-field input wires (Qx, Qy)");
+        let qx = *fp_eval.memory.get(qx_start);
+        let qy = *fp_eval.memory.get(qx_start + 1);
+
+        let (s_start, s_end) = in_ranges[1];
+        ensure!(s_end - s_start + 1 == 3, "expected 3 scalar-field input wires (e, r, s)");
+        let e = *fn_eval.memory.get(s_start);
+        let r = *fn_eval.memory.get(s_start + 1);
+        let s = *fn_eval.memory.get(s_start + 2);
+
+        let q = Point {
+            x: qx,
+            y: qy,
+            inf: fp_eval.backend.input_public(Secp256k1::ZERO)?,
+        };
+        // Q is untrusted input (part of `in_ranges`), so its on-curve-ness
+        // must be constrained here, not merely assumed.
+        assert_on_curve(&mut fp_eval.backend, &q)?;
+
+        // w = s^-1, u1 = e*w, u2 = r*w, over the scalar field.
+        let (u1, u2) = compute_scalars(&mut fn_eval.backend, &e, &r, &s)?;
+
+        // Lift both scalars' bits across to the base field via the same
+        // F2 bit-currency the `Conv` gate itself uses.
+        let u1_bits = scalar_bits_in_other_field(&mut fn_eval.backend, &mut fp_eval.backend, &u1)?;
+        let u2_bits = scalar_bits_in_other_field(&mut fn_eval.backend, &mut fp_eval.backend, &u2)?;
+
+        let term1 = fixed_base_mult(&mut fp_eval.backend, &u1_bits, plugin.g_table())?;
+        let term2 = variable_base_mult(&mut fp_eval.backend, &u2_bits, &q)?;
+        let point_r = ec_add(&mut fp_eval.backend, &term1, &term2)?;
+
+        // R must not be the identity.
+        fp_eval.backend.assert_zero(&point_r.inf)?;
+
+        // Rx mod n == r: n < p for secp256k1, so a single "subtract n once"
+        // witness bit suffices to reduce Rx into [0, n).
+        let order_fp = order_as_base_field();
+        let rx_val = fp_eval.backend.wire_value(&point_r.x);
+        let reduce_val = rx_val.map(|v| {
+            if v.into_int() >= order_fp.into_int() {
+                Secp256k1::ONE
+            } else {
+                Secp256k1::ZERO
+            }
+        });
+        let reduce = fp_eval.backend.input_private(reduce_val)?;
+        let not_reduce = fp_eval
+            .backend
+            .add_constant(&fp_eval.backend.mul_constant(&reduce, -Secp256k1::ONE)?, Secp256k1::ONE)?;
+        let reduce_is_bool = fp_eval.backend.mul(&reduce, &not_reduce)?;
+        fp_eval.backend.assert_zero(&reduce_is_bool)?;
+
+        let order_wire = fp_eval.backend.constant(order_fp)?;
+        let subtrahend = fp_eval.backend.mul(&reduce, &order_wire)?;
+        let adjusted = fp_eval.backend.sub(&point_r.x, &subtrahend)?;
+
+        // Decompose `adjusted` into F2 bits, both to range-check it against
+        // `n` on the base-field side and to recompose it as a scalar-field
+        // value to compare against `r`.
+        let adjusted_bits = fp_eval.backend.assert_conv_to_bits(&adjusted)?;
+
+        let mut adjusted_bits_fp: Vec<_> = adjusted_bits
+            .iter()
+            .map(|bit| lift_bit(&mut fp_eval.backend, bit))
+            .collect::<Result<_>>()?;
+        adjusted_bits_fp.reverse(); // little-endian -> most-significant-first
+        let bound_bits = order_bits_msb_first(adjusted_bits_fp.len());
+        assert_lt_public(&mut fp_eval.backend, &adjusted_bits_fp, &bound_bits)?;
+
+        let adjusted_in_fn = fn_eval.backend.assert_conv_from_bits(&adjusted_bits)?;
+        let diff = fn_eval.backend.sub(&adjusted_in_fn, &r)?;
+        fn_eval.backend.assert_zero(&diff)
+    }
+
     #[inline]
     fn evaluate_call_gate(
         &mut self,
@@ -1717,6 +2868,49 @@ impl<C: AbstractChannel + 'static> EvaluatorCirc<C> {
                         &body.execution(),
                     )?;
                 }
+                PluginExecution::Lookup(plugin) => {
+                    let type_id = plugin.type_id() as usize;
+                    self.callframe_start(func, out_ranges, in_ranges)?;
+                    self.eval[type_id].plugin_call_gate(
+                        out_ranges,
+                        in_ranges,
+                        &body.execution(),
+                    )?;
+                    self.callframe_end(func);
+                }
+                PluginExecution::GaloisLookup(plugin) => {
+                    self.eval[plugin.field() as usize].plugin_call_gate(
+                        out_ranges,
+                        in_ranges,
+                        &body.execution(),
+                    )?;
+                }
+                PluginExecution::Sha256(plugin) => {
+                    let type_id = plugin.type_id() as usize;
+                    self.callframe_start(func, out_ranges, in_ranges)?;
+                    self.eval[type_id].plugin_call_gate(
+                        out_ranges,
+                        in_ranges,
+                        &body.execution(),
+                    )?;
+                    self.callframe_end(func);
+                }
+                PluginExecution::LessEqThan(plugin) => {
+                    let type_id = plugin.type_id() as usize;
+                    self.callframe_start(func, out_ranges, in_ranges)?;
+                    self.eval[type_id].plugin_call_gate(
+                        out_ranges,
+                        in_ranges,
+                        &body.execution(),
+                    )?;
+                    self.callframe_end(func);
+                }
+                PluginExecution::EcdsaVerify(plugin) => {
+                    // Two field backends at once, so this skips both the
+                    // callframe and the single-field `plugin_call_gate`
+                    // path (`Disjunction`/`Ram` skip only the callframe).
+                    self.eval_ecdsa_verify(plugin, in_ranges)?;
+                }
             },
         };
 
@@ -1816,7 +3010,7 @@ pub(crate) mod tests {
         circuit_ir::{CircInputs, FunStore, FuncDecl, GateM, WireId, WireRange},
         fields::{F384P_MODULUS, F384Q_MODULUS},
     };
-    use mac_n_cheese_sieve_parser::Number;
+    use mac_n_cheese_sieve_parser::{Number, PluginTypeArg};
     use ocelot::svole::{LPN_EXTEND_SMALL, LPN_SETUP_SMALL};
     use pretty_env_logger;
     use rand::SeedableRng;
@@ -1933,6 +3127,7 @@ pub(crate) mod tests {
             )?;
             eval.load_backends(&mut channel, true)?;
             eval.evaluate_gates(&gates_prover, &func_store_prover)?;
+            eval.finish()?;
             eyre::Result::Ok(())
         });
 
@@ -1959,6 +3154,7 @@ pub(crate) mod tests {
         .unwrap();
         eval.load_backends(&mut channel, true)?;
         eval.evaluate_gates(&gates, &func_store)?;
+        eval.finish()?;
 
         handle.join().unwrap()
     }
@@ -2257,6 +3453,54 @@ pub(crate) mod tests {
         test_circuit(fields, func_store, gates, instances, witnesses).unwrap();
     }
 
+    fn test_ecdsa_verify_rejects_off_curve_public_key() {
+        // `(1, 1)` is not on secp256k1 (y^2 == x^3 + 7): 1^2 != 1^3 + 7.
+        // `assert_on_curve` runs before any of the signature math, so this
+        // must be rejected regardless of what `(e, r, s)` are.
+        let fields = vec![SECP256K1_MODULUS, SECP256K1ORDER_MODULUS];
+        let type_store = TypeStore::try_from(fields.clone()).unwrap();
+        let mut func_store = FunStore::default();
+
+        let func = FuncDecl::new_plugin(
+            vec![],
+            vec![(FF0, 2), (FF1, 3)],
+            "ecdsa_verify_v0".into(),
+            "ecdsa_verify_v0".into(),
+            Vec::<PluginTypeArg>::new(),
+            vec![],
+            vec![],
+            &type_store,
+            &func_store,
+        )
+        .unwrap();
+        func_store.insert("ecdsa_verify_v0".into(), func);
+
+        let gates = vec![
+            GateM::Witness(FF0, 0), // Qx
+            GateM::Witness(FF0, 1), // Qy
+            GateM::Witness(FF1, 0), // e
+            GateM::Witness(FF1, 1), // r
+            GateM::Witness(FF1, 2), // s
+            GateM::Call(Box::new((
+                "ecdsa_verify_v0".into(),
+                vec![],
+                vec![(0, 1), (0, 2)],
+            ))),
+        ];
+
+        let instances = vec![vec![], vec![]];
+        let witnesses = vec![
+            vec![Secp256k1::ONE.into_int(), Secp256k1::ONE.into_int()],
+            vec![
+                Secp256k1order::ONE.into_int(),
+                Secp256k1order::ONE.into_int(),
+                Secp256k1order::ONE.into_int(),
+            ],
+        ];
+
+        assert!(test_circuit(fields, func_store, gates, instances, witnesses).is_err());
+    }
+
     fn test4_simple_fun() {
         // tests the simplest function
 
@@ -2649,6 +3893,11 @@ pub(crate) mod tests {
         test_conv_ff_5();
     }
 
+    #[test]
+    fn test_ecdsa_verify() {
+        test_ecdsa_verify_rejects_off_curve_public_key();
+    }
+
     #[test]
     fn test_func() {
         test4_simple_fun();
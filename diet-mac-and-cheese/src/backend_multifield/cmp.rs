@@ -0,0 +1,70 @@
+//! Secret-vs-secret bit comparisons, generalizing the existing
+//! secret-vs-*public* `less_eq_than_with_public2` recurrence to two
+//! committed little-endian bit vectors. Like the hash gadgets in
+//! [`super::sha256`], these run entirely on a `BackendT` over `F2`, using
+//! `add`/`mul`/`add_constant` for XOR/AND/NOT.
+
+use eyre::Result;
+
+use crate::backend_trait::BackendT;
+
+fn bit_constant<B: BackendT>(backend: &mut B, bit: bool) -> Result<B::Wire> {
+    let val = if bit { backend.one()? } else { backend.zero()? };
+    backend.constant(val)
+}
+
+fn xor<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.add(a, b)
+}
+
+fn and<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.mul(a, b)
+}
+
+fn not<B: BackendT>(backend: &mut B, a: &B::Wire) -> Result<B::Wire> {
+    let one = backend.one()?;
+    backend.add_constant(a, one)
+}
+
+/// A bit wire that is 1 iff `a < b`, for `a`/`b` equal-length little-endian
+/// bit vectors, via borrow-propagating subtraction from the LSB up:
+/// `c_{i+1} = (¬a_i ∧ b_i) ⊕ (¬(a_i ⊕ b_i) ∧ c_i)`. The final borrow is 1
+/// iff `a < b`.
+pub(crate) fn less_than<B: BackendT>(
+    backend: &mut B,
+    a: &[B::Wire],
+    b: &[B::Wire],
+) -> Result<B::Wire> {
+    assert_eq!(a.len(), b.len());
+    let mut borrow = bit_constant(backend, false)?;
+    for i in 0..a.len() {
+        let axb = xor(backend, &a[i], &b[i])?;
+        let not_a = not(backend, &a[i])?;
+        let term1 = and(backend, &not_a, &b[i])?;
+        let not_axb = not(backend, &axb)?;
+        let term2 = and(backend, &not_axb, &borrow)?;
+        borrow = xor(backend, &term1, &term2)?;
+    }
+    Ok(borrow)
+}
+
+/// A bit wire that is 1 iff `a == b`: the AND, over every bit position, of
+/// `¬(a_i ⊕ b_i)`.
+pub(crate) fn equal<B: BackendT>(backend: &mut B, a: &[B::Wire], b: &[B::Wire]) -> Result<B::Wire> {
+    assert_eq!(a.len(), b.len());
+    let mut acc = bit_constant(backend, true)?;
+    for i in 0..a.len() {
+        let axb = xor(backend, &a[i], &b[i])?;
+        let not_axb = not(backend, &axb)?;
+        acc = and(backend, &acc, &not_axb)?;
+    }
+    Ok(acc)
+}
+
+/// A bit wire that is 1 iff `a <= b`, i.e. `(a < b) XOR (a == b)` (the two
+/// are mutually exclusive, so XOR is OR here).
+pub(crate) fn less_eq<B: BackendT>(backend: &mut B, a: &[B::Wire], b: &[B::Wire]) -> Result<B::Wire> {
+    let lt = less_than(backend, a, b)?;
+    let eq = equal(backend, a, b)?;
+    xor(backend, &lt, &eq)
+}
@@ -0,0 +1,417 @@
+//! In-circuit ECDSA-over-secp256k1 verification, generic over `BackendT`/
+//! `BackendConvT` so the same gadget code runs for both `Prover` and
+//! `Verifier`. See `EvaluatorCirc::eval_ecdsa_verify` for how this is wired
+//! to the two concrete field backends (`Secp256k1` for the curve,
+//! `Secp256k1order` for the scalars).
+//!
+//! Scope, stated up front: the affine addition formula below does not
+//! special-case `P == -Q` (it would need to yield the identity, but the
+//! chord/tangent selection here always treats a shared `x`-coordinate as a
+//! doubling). The accumulator starts at, and the variable-base ladder point
+//! never becomes, the identity except possibly in the final sum `R` — which
+//! is exactly the case callers are asked to check (`R` not the identity)
+//! anyway, so that check still does its job.
+
+use super::{BackendConvT, MacBitGeneric};
+use crate::backend_trait::BackendT;
+use eyre::Result;
+use swanky_field::{FiniteField, FiniteRing, PrimeFiniteField};
+use swanky_field_ff_primes::Secp256k1;
+
+/// Build a field element from a 64-bit limb using only `ZERO`/`ONE`/`+`, so
+/// we don't have to guess at whatever bignum-literal API the concrete field
+/// type exposes.
+fn fe_from_u64<FE: PrimeFiniteField>(v: u64) -> FE {
+    let mut acc = FE::ZERO;
+    let mut bit = FE::ONE;
+    let mut v = v;
+    for _ in 0..64 {
+        if v & 1 == 1 {
+            acc = acc + bit;
+        }
+        bit = bit + bit;
+        v >>= 1;
+    }
+    acc
+}
+
+/// As [`fe_from_u64`], for a big-endian 256-bit value given as four 64-bit
+/// limbs.
+fn fe_from_u64_limbs<FE: PrimeFiniteField>(limbs: [u64; 4]) -> FE {
+    let mut acc = FE::ZERO;
+    for limb in limbs {
+        for _ in 0..64 {
+            acc = acc + acc;
+        }
+        acc = acc + fe_from_u64(limb);
+    }
+    acc
+}
+
+const GX_LIMBS: [u64; 4] = [
+    0x79BE667EF9DCBBAC,
+    0x55A06295CE870B07,
+    0x029BFCDB2DCE28D9,
+    0x59F2815B16F81798,
+];
+const GY_LIMBS: [u64; 4] = [
+    0x483ADA7726A3C465,
+    0x5DA4FBFC0E1108A8,
+    0xFD17B448A6855419,
+    0x9C47D08FFB10D4B8,
+];
+/// The order of the secp256k1 group, as a `Secp256k1` (base-field) element,
+/// needed to embed it as a constant in the `Rx mod n` check, which runs over
+/// the base field.
+const N_LIMBS: [u64; 4] = [
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFE,
+    0xBAAEDCE6AF48A03B,
+    0xBFD25E8CD0364141,
+];
+
+/// An affine secp256k1 point; `inf = 1` marks the point at infinity (in
+/// which case `x`/`y` are unused, conventionally `0`).
+#[derive(Clone)]
+pub(crate) struct Point<W> {
+    pub x: W,
+    pub y: W,
+    pub inf: W,
+}
+
+/// `bit ? a : b`, applied componentwise to two points (including the `inf`
+/// flag, so selecting against the identity point correctly propagates
+/// "is this the identity").
+fn select<B: BackendT>(
+    backend: &mut B,
+    bit: &B::Wire,
+    a: &Point<B::Wire>,
+    b: &Point<B::Wire>,
+) -> Result<Point<B::Wire>> {
+    let sel = |backend: &mut B, a: &B::Wire, b: &B::Wire| -> Result<B::Wire> {
+        let diff = backend.sub(a, b)?;
+        let scaled = backend.mul(bit, &diff)?;
+        backend.add(b, &scaled)
+    };
+    Ok(Point {
+        x: sel(backend, &a.x, &b.x)?,
+        y: sel(backend, &a.y, &b.y)?,
+        inf: sel(backend, &a.inf, &b.inf)?,
+    })
+}
+
+/// `1` iff `x == 0`, via the standard witness-inverse trick: the prover
+/// supplies `inv` with `x*inv == 1 - is_zero` and `x*is_zero == 0`.
+fn is_zero<B: BackendT>(backend: &mut B, x: &B::Wire) -> Result<B::Wire> {
+    let value = backend.wire_value(x);
+    let is_zero_val = value.map(|v| {
+        if v == B::FieldElement::ZERO {
+            B::FieldElement::ONE
+        } else {
+            B::FieldElement::ZERO
+        }
+    });
+    let inv_val = value.map(|v| {
+        if v == B::FieldElement::ZERO {
+            B::FieldElement::ZERO
+        } else {
+            v.inverse()
+        }
+    });
+    let is_zero = backend.input_private(is_zero_val)?;
+    let inv = backend.input_private(inv_val)?;
+
+    // x * is_zero == 0
+    let check1 = backend.mul(x, &is_zero)?;
+    backend.assert_zero(&check1)?;
+
+    // x * inv == 1 - is_zero
+    let x_inv = backend.mul(x, &inv)?;
+    let one_minus_is_zero = backend.add_constant(&backend.mul_constant(&is_zero, -B::FieldElement::ONE)?, B::FieldElement::ONE)?;
+    let hope_zero = backend.sub(&x_inv, &one_minus_is_zero)?;
+    backend.assert_zero(&hope_zero)?;
+
+    Ok(is_zero)
+}
+
+/// Affine addition of two *non-identity* points, assumed not to be negatives
+/// of one another (see module doc). Selects the chord or tangent lambda
+/// depending on whether the two `x`-coordinates coincide.
+fn ec_add_raw<B: BackendT>(backend: &mut B, p: &Point<B::Wire>, q: &Point<B::Wire>) -> Result<Point<B::Wire>> {
+    let dx = backend.sub(&p.x, &q.x)?;
+    let same_x = is_zero(backend, &dx)?;
+    let not_same_x = backend.add_constant(&backend.mul_constant(&same_x, -B::FieldElement::ONE)?, B::FieldElement::ONE)?;
+
+    // chord: lambda_add = (qy - py) / (px - qx); the divisor is guarded so
+    // it's never zero (the actual value is unused when same_x = 1).
+    let dy = backend.sub(&q.y, &p.y)?;
+    let dx_or_one = {
+        let masked = backend.mul(&dx, &not_same_x)?;
+        backend.add(&masked, &same_x)?
+    };
+    let dx_inv = invert(backend, &dx_or_one)?;
+    let lambda_add = backend.mul(&dy, &dx_inv)?;
+
+    // tangent: lambda_dbl = 3*px^2 / (2*py)
+    let px2 = backend.mul(&p.x, &p.x)?;
+    let three = B::FieldElement::ONE + B::FieldElement::ONE + B::FieldElement::ONE;
+    let numer = backend.mul_constant(&px2, three)?;
+    let two_py = backend.add(&p.y, &p.y)?;
+    // 2*py is zero for the identity placeholder (0, 0); guard the divisor
+    // like the chord's (`ec_add` discards this result for the identity).
+    let py_zero = is_zero(backend, &two_py)?;
+    let two_py_or_one = backend.add(&two_py, &py_zero)?;
+    let two_py_inv = invert(backend, &two_py_or_one)?;
+    let lambda_dbl = backend.mul(&numer, &two_py_inv)?;
+
+    // lambda = lambda_add + same_x * (lambda_dbl - lambda_add)
+    let diff = backend.sub(&lambda_dbl, &lambda_add)?;
+    let scaled = backend.mul(&same_x, &diff)?;
+    let lambda = backend.add(&lambda_add, &scaled)?;
+
+    let lambda2 = backend.mul(&lambda, &lambda)?;
+    let x3 = {
+        let t = backend.sub(&lambda2, &p.x)?;
+        backend.sub(&t, &q.x)?
+    };
+    let y3 = {
+        let t = backend.sub(&p.x, &x3)?;
+        let t = backend.mul(&lambda, &t)?;
+        backend.sub(&t, &p.y)?
+    };
+    let inf = backend.input_public(B::FieldElement::ZERO)?;
+    Ok(Point { x: x3, y: y3, inf })
+}
+
+/// Witness the inverse of `x` (assumed nonzero) and assert `x * inv == 1`.
+fn invert<B: BackendT>(backend: &mut B, x: &B::Wire) -> Result<B::Wire> {
+    let v = backend.wire_value(x).map(|v| v.inverse());
+    let inv = backend.input_private(v)?;
+    let check = backend.mul(x, &inv)?;
+    let hope_one = backend.add_constant(&check, -B::FieldElement::ONE)?;
+    backend.assert_zero(&hope_one)?;
+    Ok(inv)
+}
+
+/// Assert that `p` lies on secp256k1 (`y^2 == x^3 + 7`). Called once on the
+/// untrusted public key `Q` before it's used in [`variable_base_mult`] --
+/// without this, a prover could pick an arbitrary off-curve `Qx,Qy` and
+/// consistent chord/tangent witnesses at every `ec_add`/`ec_double` call to
+/// "verify" a fabricated signature for any `(h, r, s, Q)` of their choosing.
+pub(crate) fn assert_on_curve<B: BackendT<FieldElement = Secp256k1>>(backend: &mut B, p: &Point<B::Wire>) -> Result<()> {
+    let x2 = backend.mul(&p.x, &p.x)?;
+    let x3 = backend.mul(&x2, &p.x)?;
+    let y2 = backend.mul(&p.y, &p.y)?;
+    let lhs = backend.sub(&y2, &x3)?;
+    let mut seven = Secp256k1::ZERO;
+    for _ in 0..7 {
+        seven = seven + Secp256k1::ONE;
+    }
+    let hope_zero = backend.add_constant(&lhs, -seven)?;
+    backend.assert_zero(&hope_zero)
+}
+
+/// Full affine addition including identity handling: `p + q`, for any `p`,
+/// `q` (identity or not). See [`ec_add_raw`] for the non-identity case and
+/// its scope limitation.
+pub(crate) fn ec_add<B: BackendT>(backend: &mut B, p: &Point<B::Wire>, q: &Point<B::Wire>) -> Result<Point<B::Wire>> {
+    let raw = ec_add_raw(backend, p, q)?;
+    let sel1 = select(backend, &q.inf, p, &raw)?;
+    select(backend, &p.inf, q, &sel1)
+}
+
+fn identity_point<B: BackendT>(backend: &mut B) -> Result<Point<B::Wire>> {
+    Ok(Point {
+        x: backend.input_public(B::FieldElement::ZERO)?,
+        y: backend.input_public(B::FieldElement::ZERO)?,
+        inf: backend.input_public(B::FieldElement::ONE)?,
+    })
+}
+
+/// Conditionally add `term` (assumed never the identity) into `acc`:
+/// `acc + (bit ? term : O)`.
+pub(crate) fn conditional_add<B: BackendT>(
+    backend: &mut B,
+    acc: &Point<B::Wire>,
+    term: &Point<B::Wire>,
+    bit: &B::Wire,
+) -> Result<Point<B::Wire>> {
+    let identity = identity_point(backend)?;
+    let eff_term = select(backend, bit, term, &identity)?;
+    ec_add(backend, acc, &eff_term)
+}
+
+/// Plain affine doubling of a non-identity, non-2-torsion point (`py != 0`
+/// is assumed, which always holds for secp256k1's generator and any honest
+/// public key or its ladder doublings).
+pub(crate) fn ec_double<B: BackendT>(backend: &mut B, p: &Point<B::Wire>) -> Result<Point<B::Wire>> {
+    ec_add_raw(backend, p, p)
+}
+
+/// Precompute `[G, 2G, 4G, ..., 2^(len-1) G]` using plain (non-circuit)
+/// `Secp256k1` field arithmetic, so the fixed-base ladder below never has to
+/// double `G` itself inside the circuit.
+pub(crate) fn precompute_g_table(len: usize) -> Vec<(Secp256k1, Secp256k1)> {
+    let mut g = (
+        fe_from_u64_limbs::<Secp256k1>(GX_LIMBS),
+        fe_from_u64_limbs::<Secp256k1>(GY_LIMBS),
+    );
+    let mut table = Vec::with_capacity(len);
+    for _ in 0..len {
+        table.push(g);
+        let (x, y) = g;
+        let lambda = (x * x + x * x + x * x) * (y + y).inverse();
+        let x3 = lambda * lambda - x - x;
+        let y3 = lambda * (x - x3) - y;
+        g = (x3, y3);
+    }
+    table
+}
+
+/// The secp256k1 order `n`, as a base-field (`Secp256k1`) constant, used by
+/// the final `Rx mod n` range-reduction, and as the public bound for
+/// [`assert_lt_public`].
+pub(crate) fn order_as_base_field() -> Secp256k1 {
+    fe_from_u64_limbs::<Secp256k1>(N_LIMBS)
+}
+
+/// The bits of the secp256k1 order `n`, most-significant first, padded with
+/// leading zeros (or truncated) to `len`.
+pub(crate) fn order_bits_msb_first(len: usize) -> Vec<bool> {
+    let mut n_bits = Vec::with_capacity(256);
+    for &limb in N_LIMBS.iter() {
+        for i in (0..64).rev() {
+            n_bits.push((limb >> i) & 1 == 1);
+        }
+    }
+    // n_bits is now MSB-first, 256 long; pad with leading zeros (or keep
+    // only the `len` most-significant bits) so the length matches `bits`.
+    if len >= n_bits.len() {
+        let mut padded = vec![false; len - n_bits.len()];
+        padded.extend(n_bits);
+        padded
+    } else {
+        n_bits.truncate(len);
+        n_bits
+    }
+}
+
+/// `w = s^{-1} mod n`, `u1 = e*w mod n`, `u2 = r*w mod n`, all plain `Bn`
+/// arithmetic with a `wire_value`-derived witness for the inverse (the same
+/// pattern as `plugins::lookup::LookupV0::commit_inverse`).
+pub(crate) fn compute_scalars<Bn: BackendT>(
+    backend: &mut Bn,
+    e: &Bn::Wire,
+    r: &Bn::Wire,
+    s: &Bn::Wire,
+) -> Result<(Bn::Wire, Bn::Wire)> {
+    let w = invert(backend, s)?;
+    let u1 = backend.mul(e, &w)?;
+    let u2 = backend.mul(r, &w)?;
+    Ok((u1, u2))
+}
+
+/// Decompose `scalar` (a `Bn::Wire`) into its bits via `assert_conv_to_bits`
+/// and lift each one individually into a `Bp`-field 0/1 wire via
+/// `assert_conv_from_bits` on a single-element slice — the same
+/// `GateM::Conv` bit-currency that bridges any two conversion backends,
+/// applied bit-by-bit instead of as one recomposition. Returned
+/// little-endian, matching `assert_conv_to_bits`'s own bit order.
+pub(crate) fn scalar_bits_in_other_field<Bn: BackendConvT, Bp: BackendConvT>(
+    fnn: &mut Bn,
+    fp: &mut Bp,
+    scalar: &Bn::Wire,
+) -> Result<Vec<Bp::Wire>> {
+    let bits = fnn.assert_conv_to_bits(scalar)?;
+    bits.iter()
+        .map(|bit| fp.assert_conv_from_bits(std::slice::from_ref(bit)))
+        .collect()
+}
+
+/// `Σ bit_i * table[i]`, never doubling `G` in-circuit. `bits` are
+/// little-endian (`bits[i]` selects `table[i]` = `2^i * G`).
+pub(crate) fn fixed_base_mult<B: BackendT<FieldElement = Secp256k1>>(
+    backend: &mut B,
+    bits: &[B::Wire],
+    table: &[(Secp256k1, Secp256k1)],
+) -> Result<Point<B::Wire>> {
+    assert_eq!(bits.len(), table.len());
+    let mut acc = identity_point(backend)?;
+    for (bit, (tx, ty)) in bits.iter().zip(table.iter()) {
+        let term = Point {
+            x: backend.input_public(*tx)?,
+            y: backend.input_public(*ty)?,
+            inf: backend.input_public(Secp256k1::ZERO)?,
+        };
+        acc = conditional_add(backend, &acc, &term, bit)?;
+    }
+    Ok(acc)
+}
+
+/// Double-and-add scalar multiplication of the witness point `base`
+/// (assumed not the identity; see module doc). `bits` are little-endian.
+pub(crate) fn variable_base_mult<B: BackendT>(
+    backend: &mut B,
+    bits: &[B::Wire],
+    base: &Point<B::Wire>,
+) -> Result<Point<B::Wire>>
+where
+    B::Wire: Clone,
+{
+    let mut acc = identity_point(backend)?;
+    let mut cur = base.clone();
+    for bit in bits {
+        acc = conditional_add(backend, &acc, &cur, bit)?;
+        cur = ec_double(backend, &cur)?;
+    }
+    Ok(acc)
+}
+
+/// Assert `bits < bound_bits` (strictly). Generalizes the MSB-to-LSB
+/// recurrence of `plugins::cmp::CmpV0::accumulate` from the boolean
+/// backend, where `add` is XOR, to any `BackendT`: the XOR of two 0/1 wires over a general field
+/// is `a + b - 2ab`, so every fold below is written out that way instead of
+/// relying on the field's own addition being XOR. `bound_bits` is a
+/// compile-time-known (public) bound, most-significant bit first, matching
+/// `bits`.
+pub(crate) fn assert_lt_public<B: BackendT>(backend: &mut B, bits: &[B::Wire], bound_bits: &[bool]) -> Result<()> {
+    assert_eq!(bits.len(), bound_bits.len());
+    let one = B::FieldElement::ONE;
+    let mut act = backend.input_public(one)?;
+    let mut r = backend.input_public(B::FieldElement::ZERO)?;
+
+    for (a_i, &b_i) in bits.iter().zip(bound_bits.iter()) {
+        // xor_i = a_i ⊕ b_i = a_i + b_i - 2*a_i*b_i; with b_i a constant,
+        // that's a_i unchanged (b_i = 0) or 1 - a_i (b_i = 1).
+        let xor_i = if b_i {
+            backend.add_constant(&backend.mul_constant(a_i, -one)?, one)?
+        } else {
+            backend.add_constant(a_i, B::FieldElement::ZERO)?
+        };
+        let one_minus_xor = backend.add_constant(&backend.mul_constant(&xor_i, -one)?, one)?;
+        let act_prime = backend.mul(&act, &one_minus_xor)?;
+
+        let r_plus_one = backend.add_constant(&r, one)?;
+        // p1 = a_i * (1 - b_i): a_i unchanged when b_i = 0, else 0.
+        let p1 = if b_i {
+            backend.mul_constant(a_i, B::FieldElement::ZERO)?
+        } else {
+            backend.add_constant(a_i, B::FieldElement::ZERO)?
+        };
+        let act_p1 = backend.mul(&act, &p1)?;
+        let p2 = backend.mul(&r_plus_one, &act_p1)?;
+        r = backend.add(&r, &p2)?;
+
+        act = act_prime;
+    }
+    // r == 0 rules out `bits > bound`; act == 0 rules out equality.
+    backend.assert_zero(&r)?;
+    backend.assert_zero(&act)
+}
+
+/// Turn a single `MacBitGeneric` F2 bit into an individual 0/1 wire of `B`,
+/// via `assert_conv_from_bits` on a one-element slice (the trivial case of
+/// bit-recomposition: the "recomposed value" of one bit is just that bit).
+pub(crate) fn lift_bit<B: BackendConvT>(backend: &mut B, bit: &MacBitGeneric) -> Result<B::Wire> {
+    backend.assert_conv_from_bits(std::slice::from_ref(bit))
+}
@@ -0,0 +1,369 @@
+//! SHA-256 and BLAKE2s compression, expressed directly over committed GF(2)
+//! wires.
+//!
+//! These gadgets are meant to run on the cheap `dmc_f2` sub-backend that
+//! [`super::DietMacAndCheeseConvProver`]/[`super::DietMacAndCheeseConvVerifier`]
+//! already hold, taking the [`MacBitGeneric`](super::MacBitGeneric) vectors
+//! produced by [`super::BackendConvT::assert_conv_to_bits`] straight in,
+//! without round-tripping through a prime field. Everything here is built
+//! from the three primitives a `BackendT` gives us over `F2`: XOR is
+//! [`BackendT::add`], AND is [`BackendT::mul`], and NOT is
+//! [`BackendT::add_constant`] by one. Rotations and shifts add no XOR/AND
+//! gates: they're index permutations of the bit vector, emitted as
+//! `BackendT::copy`s (plus zero constants for a shift's vacated bits).
+//!
+//! Both hashes are implemented against a single 512-bit input block (one
+//! SHA-256/BLAKE2s compression call from the fixed IV), matching the scope
+//! callers need to prove knowledge of a one-block preimage; chaining across
+//! multiple blocks is left to the caller.
+
+use eyre::Result;
+
+use crate::backend_trait::BackendT;
+
+/// A 32-bit word as 32 wires, least-significant bit first (matching the
+/// little-endian bit order `assert_conv_to_bits` already uses elsewhere in
+/// this module).
+type Word<B> = Vec<<B as BackendT>::Wire>;
+
+fn bit_constant<B: BackendT>(backend: &mut B, bit: bool) -> Result<B::Wire> {
+    let val = if bit { backend.one()? } else { backend.zero()? };
+    backend.constant(val)
+}
+
+fn word_constant<B: BackendT>(backend: &mut B, mut n: u32) -> Result<Word<B>> {
+    let mut out = Vec::with_capacity(32);
+    for _ in 0..32 {
+        out.push(bit_constant(backend, n & 1 == 1)?);
+        n >>= 1;
+    }
+    Ok(out)
+}
+
+fn xor<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.add(a, b)
+}
+
+fn and<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.mul(a, b)
+}
+
+fn not<B: BackendT>(backend: &mut B, a: &B::Wire) -> Result<B::Wire> {
+    let one = backend.one()?;
+    backend.add_constant(a, one)
+}
+
+fn xor_word<B: BackendT>(backend: &mut B, a: &Word<B>, b: &Word<B>) -> Result<Word<B>> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| xor(backend, x, y))
+        .collect()
+}
+
+fn and_word<B: BackendT>(backend: &mut B, a: &Word<B>, b: &Word<B>) -> Result<Word<B>> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| and(backend, x, y))
+        .collect()
+}
+
+fn not_word<B: BackendT>(backend: &mut B, a: &Word<B>) -> Result<Word<B>> {
+    a.iter().map(|x| not(backend, x)).collect()
+}
+
+/// Rotate right by `n` within a 32-bit lane. An index permutation with no
+/// XOR/AND gates: `backend.copy` is used only so every output wire is fresh
+/// (the same input wire can end up read into several output positions
+/// across a compression round).
+fn rotr<B: BackendT>(backend: &mut B, a: &Word<B>, n: usize) -> Result<Word<B>> {
+    (0..32)
+        .map(|i| backend.copy(&a[(i + n) % 32]))
+        .collect()
+}
+
+/// Logical right shift by `n`, padding the vacated high bits with zero.
+fn shr<B: BackendT>(backend: &mut B, a: &Word<B>, n: usize) -> Result<Word<B>> {
+    let mut out = Vec::with_capacity(32);
+    for i in 0..32 {
+        if i + n < 32 {
+            out.push(backend.copy(&a[i + n])?);
+        } else {
+            out.push(bit_constant(backend, false)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Ripple-carry mod-2^32 addition: for bits `a_i, b_i` and carry `c_i`,
+/// `s_i = a_i ^ b_i ^ c_i` and `c_{i+1} = a_i b_i ^ c_i (a_i ^ b_i)`.
+fn add32<B: BackendT>(backend: &mut B, a: &Word<B>, b: &Word<B>) -> Result<Word<B>> {
+    let mut out = Vec::with_capacity(32);
+    let mut carry = bit_constant(backend, false)?;
+    for i in 0..32 {
+        let a_xor_b = xor(backend, &a[i], &b[i])?;
+        out.push(xor(backend, &a_xor_b, &carry)?);
+
+        let a_and_b = and(backend, &a[i], &b[i])?;
+        let c_and_axorb = and(backend, &carry, &a_xor_b)?;
+        carry = xor(backend, &a_and_b, &c_and_axorb)?;
+    }
+    Ok(out)
+}
+
+fn add32_many<B: BackendT>(backend: &mut B, words: &[&Word<B>]) -> Result<Word<B>> {
+    let mut acc = words[0].clone();
+    for w in &words[1..] {
+        acc = add32(backend, &acc, w)?;
+    }
+    Ok(acc)
+}
+
+/// `Ch(e, f, g) = (e & f) ^ (!e & g)`.
+fn ch<B: BackendT>(backend: &mut B, e: &Word<B>, f: &Word<B>, g: &Word<B>) -> Result<Word<B>> {
+    let ef = and_word(backend, e, f)?;
+    let not_e = not_word(backend, e)?;
+    let not_e_g = and_word(backend, &not_e, g)?;
+    xor_word(backend, &ef, &not_e_g)
+}
+
+/// `Maj(a, b, c) = (a & b) ^ (a & c) ^ (b & c)`.
+fn maj<B: BackendT>(backend: &mut B, a: &Word<B>, b: &Word<B>, c: &Word<B>) -> Result<Word<B>> {
+    let ab = and_word(backend, a, b)?;
+    let ac = and_word(backend, a, c)?;
+    let bc = and_word(backend, b, c)?;
+    let ab_ac = xor_word(backend, &ab, &ac)?;
+    xor_word(backend, &ab_ac, &bc)
+}
+
+fn big_sigma0<B: BackendT>(backend: &mut B, a: &Word<B>) -> Result<Word<B>> {
+    let r1 = rotr(backend, a, 2)?;
+    let r2 = rotr(backend, a, 13)?;
+    let r3 = rotr(backend, a, 22)?;
+    let t = xor_word(backend, &r1, &r2)?;
+    xor_word(backend, &t, &r3)
+}
+
+fn big_sigma1<B: BackendT>(backend: &mut B, e: &Word<B>) -> Result<Word<B>> {
+    let r1 = rotr(backend, e, 6)?;
+    let r2 = rotr(backend, e, 11)?;
+    let r3 = rotr(backend, e, 25)?;
+    let t = xor_word(backend, &r1, &r2)?;
+    xor_word(backend, &t, &r3)
+}
+
+fn small_sigma0<B: BackendT>(backend: &mut B, w: &Word<B>) -> Result<Word<B>> {
+    let r1 = rotr(backend, w, 7)?;
+    let r2 = rotr(backend, w, 18)?;
+    let s3 = shr(backend, w, 3)?;
+    let t = xor_word(backend, &r1, &r2)?;
+    xor_word(backend, &t, &s3)
+}
+
+fn small_sigma1<B: BackendT>(backend: &mut B, w: &Word<B>) -> Result<Word<B>> {
+    let r1 = rotr(backend, w, 17)?;
+    let r2 = rotr(backend, w, 19)?;
+    let s3 = shr(backend, w, 10)?;
+    let t = xor_word(backend, &r1, &r2)?;
+    xor_word(backend, &t, &s3)
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const SHA256_H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Run the SHA-256 compression function on a single 512-bit block against an
+/// explicit 256-bit chaining state, taking and returning bit vectors in the
+/// little-endian, per-32-bit-word layout used throughout this module:
+/// `block` is 16 words and `state` is 8 words of 32 LSB-first bits each
+/// (big-endian byte order per the SHA-256 spec is the caller's
+/// responsibility to arrange into this layout); the updated 256-bit state is
+/// returned the same way. This is the primitive `crate::plugins::sha256::Sha256V0`
+/// exposes as a plugin, with `state` wired in rather than fixed.
+pub(crate) fn compress<B: BackendT>(
+    backend: &mut B,
+    block: &[B::Wire],
+    state: &[B::Wire],
+) -> Result<Vec<B::Wire>>
+where
+    B::Wire: Clone,
+{
+    assert_eq!(block.len(), 512, "sha256 gadget takes exactly one 512-bit block");
+    assert_eq!(state.len(), 256, "sha256 chaining state is exactly 256 bits");
+
+    let mut w: Vec<Word<B>> = block.chunks(32).map(|c| c.to_vec()).collect();
+    for t in 16..64 {
+        let s0 = small_sigma0(backend, &w[t - 15])?;
+        let s1 = small_sigma1(backend, &w[t - 2])?;
+        let word = add32_many(backend, &[&w[t - 16], &s0, &w[t - 7], &s1])?;
+        w.push(word);
+    }
+
+    let h0: Vec<Word<B>> = state.chunks(32).map(|c| c.to_vec()).collect();
+    let h: Vec<Word<B>> = h0.clone();
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh]: [Word<B>; 8] =
+        h.try_into().unwrap();
+
+    for t in 0..64 {
+        let s1 = big_sigma1(backend, &e)?;
+        let ch_efg = ch(backend, &e, &f, &g)?;
+        let k_t = word_constant(backend, SHA256_K[t])?;
+        let t1 = add32_many(backend, &[&hh, &s1, &ch_efg, &k_t, &w[t]])?;
+
+        let s0 = big_sigma0(backend, &a)?;
+        let maj_abc = maj(backend, &a, &b, &c)?;
+        let t2 = add32(backend, &s0, &maj_abc)?;
+
+        hh = g;
+        g = f;
+        f = e;
+        e = add32(backend, &d, &t1)?;
+        d = c;
+        c = b;
+        b = a;
+        a = add32(backend, &t1, &t2)?;
+    }
+
+    let final_words = [a, b, c, d, e, f, g, hh];
+    let mut digest = Vec::with_capacity(256);
+    for (init, work) in h0.into_iter().zip(final_words) {
+        digest.extend(add32(backend, &init, &work)?);
+    }
+    Ok(digest)
+}
+
+/// Run the SHA-256 compression function on a single 512-bit block from the
+/// fixed IV, as used by the one-block preimage gadgets in
+/// [`super::DietMacAndCheeseConvProver::sha256`]/
+/// [`super::DietMacAndCheeseConvVerifier::sha256`].
+pub(crate) fn sha256<B: BackendT>(backend: &mut B, input: &[B::Wire]) -> Result<Vec<B::Wire>>
+where
+    B::Wire: Clone,
+{
+    let mut h0 = Vec::with_capacity(256);
+    for hv in SHA256_H {
+        h0.extend(word_constant(backend, hv)?);
+    }
+    compress(backend, input, &h0)
+}
+
+const BLAKE2S_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const BLAKE2S_SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// BLAKE2s's `G` mixing function, operating in place on four of the
+/// sixteen 32-bit state words.
+#[allow(clippy::too_many_arguments)]
+fn blake2s_g<B: BackendT>(
+    backend: &mut B,
+    v: &mut [Word<B>; 16],
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    x: &Word<B>,
+    y: &Word<B>,
+) -> Result<()> {
+    v[a] = add32_many(backend, &[&v[a], &v[b], x])?;
+    v[d] = rotr(backend, &xor_word(backend, &v[d], &v[a])?, 16)?;
+    v[c] = add32(backend, &v[c], &v[d])?;
+    v[b] = rotr(backend, &xor_word(backend, &v[b], &v[c])?, 12)?;
+
+    v[a] = add32_many(backend, &[&v[a], &v[b], y])?;
+    v[d] = rotr(backend, &xor_word(backend, &v[d], &v[a])?, 8)?;
+    v[c] = add32(backend, &v[c], &v[d])?;
+    v[b] = rotr(backend, &xor_word(backend, &v[b], &v[c])?, 7)?;
+    Ok(())
+}
+
+/// Run the BLAKE2s compression function on a single 512-bit message block,
+/// as the final (and only) block of an unkeyed, 32-byte-digest hash: the
+/// byte counter is fixed at 64 and the "last block" finalization flag is
+/// set, matching what a caller hashing one 64-byte message would observe.
+/// Input/output bit layout mirrors [`sha256`]: 16 little-endian 32-bit
+/// words in, 8 little-endian 32-bit words (256 bits) out.
+pub(crate) fn blake2s<B: BackendT>(backend: &mut B, input: &[B::Wire]) -> Result<Vec<B::Wire>>
+where
+    B::Wire: Clone,
+{
+    assert_eq!(input.len(), 512, "blake2s gadget takes exactly one 512-bit block");
+
+    let m: Vec<Word<B>> = input.chunks(32).map(|c| c.to_vec()).collect();
+
+    let mut h: Vec<Word<B>> = Vec::with_capacity(8);
+    for (i, iv) in BLAKE2S_IV.into_iter().enumerate() {
+        // h[0] is further XORed with the parameter block `0x01010020`
+        // (fanout=1, depth=1, digest_length=32), the unkeyed/no-salt default.
+        let param = if i == 0 { 0x0101_0020 } else { 0 };
+        h.push(word_constant(backend, iv ^ param)?);
+    }
+
+    let mut v: [Word<B>; 16] = [
+        h[0].clone(),
+        h[1].clone(),
+        h[2].clone(),
+        h[3].clone(),
+        h[4].clone(),
+        h[5].clone(),
+        h[6].clone(),
+        h[7].clone(),
+        word_constant(backend, BLAKE2S_IV[0])?,
+        word_constant(backend, BLAKE2S_IV[1])?,
+        word_constant(backend, BLAKE2S_IV[2])?,
+        word_constant(backend, BLAKE2S_IV[3])?,
+        // v[12]/v[13] are XORed with the low/high words of the byte
+        // counter `t`; a single 64-byte block gives t = 64, t_high = 0.
+        xor_word(backend, &word_constant(backend, BLAKE2S_IV[4])?, &word_constant(backend, 64)?)?,
+        word_constant(backend, BLAKE2S_IV[5])?,
+        // Last-block flag: v[14] ^= 0xffffffff.
+        xor_word(
+            backend,
+            &word_constant(backend, BLAKE2S_IV[6])?,
+            &word_constant(backend, 0xffff_ffff)?,
+        )?,
+        word_constant(backend, BLAKE2S_IV[7])?,
+    ];
+
+    for round in 0..10 {
+        let s = BLAKE2S_SIGMA[round];
+        blake2s_g(backend, &mut v, 0, 4, 8, 12, &m[s[0]], &m[s[1]])?;
+        blake2s_g(backend, &mut v, 1, 5, 9, 13, &m[s[2]], &m[s[3]])?;
+        blake2s_g(backend, &mut v, 2, 6, 10, 14, &m[s[4]], &m[s[5]])?;
+        blake2s_g(backend, &mut v, 3, 7, 11, 15, &m[s[6]], &m[s[7]])?;
+
+        blake2s_g(backend, &mut v, 0, 5, 10, 15, &m[s[8]], &m[s[9]])?;
+        blake2s_g(backend, &mut v, 1, 6, 11, 12, &m[s[10]], &m[s[11]])?;
+        blake2s_g(backend, &mut v, 2, 7, 8, 13, &m[s[12]], &m[s[13]])?;
+        blake2s_g(backend, &mut v, 3, 4, 9, 14, &m[s[14]], &m[s[15]])?;
+    }
+
+    let mut digest = Vec::with_capacity(256);
+    for i in 0..8 {
+        let t = xor_word(backend, &v[i], &v[i + 8])?;
+        digest.extend(xor_word(backend, &h[i], &t)?);
+    }
+    Ok(digest)
+}
@@ -0,0 +1,100 @@
+//! Fixed-width (u32/u64) unsigned integer arithmetic, generalizing the
+//! bit-oriented style of [`super::cmp`]/[`super::sha256`] into reusable
+//! word ops: wrapping add, bitwise xor/and/or, and rotation, all over
+//! committed GF(2) wires. Words are little-endian bit vectors (index 0 is
+//! the LSB), the same convention `less_eq_than_with_public2`, `cmp`, and
+//! `sha256`'s internal 32-bit words already use.
+//!
+//! This is the prerequisite layer for expressing SHA-256/Keccak-style round
+//! functions directly against a live `BackendT`, rather than one-off as in
+//! [`super::sha256`]: callers pick their own word width (32 for SHA-256, 64
+//! for a Keccak-f\[1600\] lane, ...) instead of it being baked into the
+//! gadget. Comparisons are *not* reimplemented here — [`super::cmp`]'s
+//! `less_than`/`less_eq`/`equal` already operate on arbitrary-length
+//! little-endian bit vectors and apply unchanged.
+
+use eyre::Result;
+
+use crate::backend_trait::BackendT;
+
+fn bit_constant<B: BackendT>(backend: &mut B, bit: bool) -> Result<B::Wire> {
+    let val = if bit { backend.one()? } else { backend.zero()? };
+    backend.constant(val)
+}
+
+fn xor<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.add(a, b)
+}
+
+fn and<B: BackendT>(backend: &mut B, a: &B::Wire, b: &B::Wire) -> Result<B::Wire> {
+    backend.mul(a, b)
+}
+
+/// Bitwise XOR of two equal-length words.
+pub(crate) fn xor_word<B: BackendT>(
+    backend: &mut B,
+    a: &[B::Wire],
+    b: &[B::Wire],
+) -> Result<Vec<B::Wire>> {
+    assert_eq!(a.len(), b.len());
+    a.iter().zip(b.iter()).map(|(x, y)| xor(backend, x, y)).collect()
+}
+
+/// Bitwise AND of two equal-length words.
+pub(crate) fn and_word<B: BackendT>(
+    backend: &mut B,
+    a: &[B::Wire],
+    b: &[B::Wire],
+) -> Result<Vec<B::Wire>> {
+    assert_eq!(a.len(), b.len());
+    a.iter().zip(b.iter()).map(|(x, y)| and(backend, x, y)).collect()
+}
+
+/// Bitwise OR, via `a | b = (a ^ b) ^ (a & b)`.
+pub(crate) fn or_word<B: BackendT>(
+    backend: &mut B,
+    a: &[B::Wire],
+    b: &[B::Wire],
+) -> Result<Vec<B::Wire>> {
+    let x = xor_word(backend, a, b)?;
+    let n = and_word(backend, a, b)?;
+    xor_word(backend, &x, &n)
+}
+
+/// Rotate left by `n` positions within the word. A pure index permutation:
+/// the only backend call is `copy`, so every output wire is fresh (the same
+/// input wire can land in several output positions across a round).
+pub(crate) fn rotl<B: BackendT>(backend: &mut B, a: &[B::Wire], n: usize) -> Result<Vec<B::Wire>> {
+    let len = a.len();
+    (0..len)
+        .map(|i| backend.copy(&a[(i + len - (n % len)) % len]))
+        .collect()
+}
+
+/// Rotate right by `n` positions within the word; see [`rotl`].
+pub(crate) fn rotr<B: BackendT>(backend: &mut B, a: &[B::Wire], n: usize) -> Result<Vec<B::Wire>> {
+    let len = a.len();
+    (0..len).map(|i| backend.copy(&a[(i + n) % len])).collect()
+}
+
+/// Wrapping (mod `2^len`) addition by ripple-carry: `sum_i = a_i ^ b_i ^ c_i`,
+/// `c_{i+1} = (a_i & b_i) ^ (c_i & (a_i ^ b_i))`, with the final carry-out
+/// discarded to get wraparound semantics.
+pub(crate) fn add_mod<B: BackendT>(
+    backend: &mut B,
+    a: &[B::Wire],
+    b: &[B::Wire],
+) -> Result<Vec<B::Wire>> {
+    assert_eq!(a.len(), b.len());
+    let mut out = Vec::with_capacity(a.len());
+    let mut carry = bit_constant(backend, false)?;
+    for i in 0..a.len() {
+        let a_xor_b = xor(backend, &a[i], &b[i])?;
+        out.push(xor(backend, &a_xor_b, &carry)?);
+
+        let a_and_b = and(backend, &a[i], &b[i])?;
+        let c_and_axorb = and(backend, &carry, &a_xor_b)?;
+        carry = xor(backend, &a_and_b, &c_and_axorb)?;
+    }
+    Ok(out)
+}
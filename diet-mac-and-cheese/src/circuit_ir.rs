@@ -3,11 +3,12 @@
 
 use crate::{
     fields::modulus_to_type_id,
-    plugins::{DisjunctionV0, Plugin, PluginBody, PluginType, RamV0},
+    plugins::{DisjunctionV0, GaloisLookupV0, Plugin, PluginBody, PluginType, RamV0},
 };
 use eyre::{bail, eyre, Result};
 use log::debug;
 use mac_n_cheese_sieve_parser::{Number, PluginTypeArg};
+use rustc_hash::FxHashSet;
 use std::{
     cmp::max,
     collections::{BTreeMap, VecDeque},
@@ -75,6 +76,13 @@ fn size_of_gate_m_less_than_32_bytes() {
 
 impl GateM {
     /// Return the [`TypeId`] associated with this gate.
+    ///
+    /// `Conv` touches two (possibly distinct) types; this returns its
+    /// _output_ type as the primary one — use [`Self::type_ids`] for the
+    /// full set. `Call` carries no type of its own at all (its wire ranges
+    /// are typed by the callee's declared counts, which needs a
+    /// [`FunStore`] lookup to resolve), so it has no primary type to return
+    /// here either — use [`Self::type_ids`] for that case.
     pub(crate) fn type_id(&self) -> TypeId {
         use GateM::*;
         match self {
@@ -91,11 +99,42 @@ impl GateM {
             | Instance(ty, _)
             | Witness(ty, _)
             | Challenge(ty, _) => *ty,
-            Conv(_) | Call(_) => todo!(),
+            Conv(c) => c.0,
+            Call(_) => panic!("`Call` has no single `TypeId` of its own; use `type_ids` with a `FunStore` instead"),
             Comment(_) => panic!("There's no `TypeId` associated with a comment!"),
         }
     }
 
+    /// Return every [`TypeId`] this gate touches. Unlike [`Self::type_id`],
+    /// this resolves `Call`'s types too, by looking up the callee's
+    /// declared `output_counts`/`input_counts` in `fun_store`; `Conv`
+    /// returns both of its (possibly distinct) types; `Comment` touches
+    /// none.
+    pub(crate) fn type_ids(&self, fun_store: &FunStore) -> Result<Vec<TypeId>> {
+        use GateM::*;
+        Ok(match self {
+            Conv(c) => {
+                let (ty1, _, ty2, _) = c.as_ref();
+                vec![*ty1, *ty2]
+            }
+            Call(c) => {
+                let (name, _, _) = c.as_ref();
+                let decl = fun_store.get(name)?;
+                let mut types: Vec<TypeId> = decl
+                    .output_counts()
+                    .iter()
+                    .chain(decl.input_counts())
+                    .map(|(ty, _)| *ty)
+                    .collect();
+                types.sort_unstable();
+                types.dedup();
+                types
+            }
+            Comment(_) => vec![],
+            other => vec![other.type_id()],
+        })
+    }
+
     /// Return the [`WireId`] associated with the output of this gate, or
     /// `None` if the gate has no output wire.
     pub(crate) fn out_wire(&self) -> Option<WireId> {
@@ -165,48 +204,114 @@ impl TypeStore {
 }
 
 impl TryFrom<Vec<mac_n_cheese_sieve_parser::Type>> for TypeStore {
-    type Error = eyre::Error;
+    // A `TryFrom` impl only gets one `Self::Error`, so every problem found
+    // below is collected into one `Diagnostics` report rather than bailing
+    // on the first (see `crate::diagnostics` for why). `Diagnostics`
+    // implements `std::error::Error`, so `?` at call sites expecting an
+    // `eyre::Error` still works.
+    type Error = crate::diagnostics::Diagnostics;
 
     fn try_from(
         types: Vec<mac_n_cheese_sieve_parser::Type>,
     ) -> std::result::Result<Self, Self::Error> {
+        use crate::diagnostics::{Diagnostics, DiagnosticKind};
+
         debug!("Converting Circuit IR types to `TypeStore`");
+        let mut diagnostics = Diagnostics::default();
         if types.len() > 256 {
-            return Err(eyre!("Too many types specified: {} > 256", types.len()));
+            diagnostics.push(DiagnosticKind::TooManyTypes {
+                count: types.len(),
+                max: 256,
+            });
         }
+
         let mut store = TypeStore::default();
+        let mut seen_moduli: Vec<Number> = Vec::new();
         for (i, ty) in types.into_iter().enumerate() {
+            // Past 256 entries there's no `TypeId` byte left to assign;
+            // `TooManyTypes` above already reports that, so just stop
+            // registering while still finishing the scan for the other
+            // problems below.
+            if i >= 256 {
+                continue;
+            }
+            let type_id = i as u8;
             let spec = match ty {
                 mac_n_cheese_sieve_parser::Type::Field { modulus } => {
-                    TypeSpecification::Field(modulus_to_type_id(modulus)?)
+                    if seen_moduli.iter().any(|m| m == &modulus) {
+                        diagnostics.push(DiagnosticKind::DuplicateField { type_id });
+                    }
+                    seen_moduli.push(modulus.clone());
+                    match modulus_to_type_id(modulus) {
+                        Ok(id) => TypeSpecification::Field(id),
+                        Err(err) => {
+                            diagnostics.push(DiagnosticKind::UnsupportedField {
+                                type_id,
+                                reason: err.to_string(),
+                            });
+                            continue;
+                        }
+                    }
                 }
                 mac_n_cheese_sieve_parser::Type::ExtField { .. } => {
-                    bail!("Extension fields not supported!")
+                    diagnostics.push(DiagnosticKind::UnsupportedExtensionField { type_id });
+                    continue;
                 }
                 mac_n_cheese_sieve_parser::Type::PluginType(ty) => {
                     TypeSpecification::Plugin(PluginType::from(ty))
                 }
             };
-            store.insert(i as u8, spec);
+            store.insert(type_id, spec);
+        }
+
+        if diagnostics.is_empty() {
+            Ok(store)
+        } else {
+            Err(diagnostics)
         }
-        Ok(store)
     }
 }
 
 impl TryFrom<Vec<Number>> for TypeStore {
-    type Error = eyre::Error;
+    type Error = crate::diagnostics::Diagnostics;
 
     fn try_from(fields: Vec<Number>) -> std::result::Result<Self, Self::Error> {
+        use crate::diagnostics::{Diagnostics, DiagnosticKind};
+
         debug!("Converting vector of fields to `TypeStore`");
+        let mut diagnostics = Diagnostics::default();
         if fields.len() > 256 {
-            return Err(eyre!("Too many types specified: {} > 256", fields.len()));
+            diagnostics.push(DiagnosticKind::TooManyTypes {
+                count: fields.len(),
+                max: 256,
+            });
         }
+
         let mut store = TypeStore::default();
+        let mut seen_moduli: Vec<Number> = Vec::new();
         for (i, field) in fields.into_iter().enumerate() {
-            let spec = TypeSpecification::Field(modulus_to_type_id(field)?);
-            store.insert(i as u8, spec);
+            if i >= 256 {
+                continue;
+            }
+            let type_id = i as u8;
+            if seen_moduli.iter().any(|m| m == &field) {
+                diagnostics.push(DiagnosticKind::DuplicateField { type_id });
+            }
+            seen_moduli.push(field.clone());
+            match modulus_to_type_id(field) {
+                Ok(id) => store.insert(type_id, TypeSpecification::Field(id)),
+                Err(err) => diagnostics.push(DiagnosticKind::UnsupportedField {
+                    type_id,
+                    reason: err.to_string(),
+                }),
+            }
+        }
+
+        if diagnostics.is_empty() {
+            Ok(store)
+        } else {
+            Err(diagnostics)
         }
-        Ok(store)
     }
 }
 
@@ -430,7 +535,11 @@ impl FuncDecl {
         type_store: &TypeStore,
         fun_store: &FunStore,
     ) -> Result<Self> {
-        use crate::plugins::{GaloisPolyV0, IterV0, MuxV0, MuxV1, PermutationCheckV1, VectorsV1};
+        use crate::plugins::ecdsa::EcdsaVerifyV0;
+        use crate::plugins::{
+            CmpV0, GaloisPolyV0, IterV0, LookupV0, MuxV0, MuxV1, PermutationCheckV1, Sha256V0,
+            VectorsV1,
+        };
 
         let execution = match plugin_name.as_str() {
             MuxV0::NAME => MuxV0::instantiate(
@@ -497,6 +606,46 @@ impl FuncDecl {
                 type_store,
                 fun_store,
             )?,
+            LookupV0::NAME => LookupV0::instantiate(
+                &operation,
+                &params,
+                &output_counts,
+                &input_counts,
+                type_store,
+                fun_store,
+            )?,
+            GaloisLookupV0::NAME => GaloisLookupV0::instantiate(
+                &operation,
+                &params,
+                &output_counts,
+                &input_counts,
+                type_store,
+                fun_store,
+            )?,
+            Sha256V0::NAME => Sha256V0::instantiate(
+                &operation,
+                &params,
+                &output_counts,
+                &input_counts,
+                type_store,
+                fun_store,
+            )?,
+            CmpV0::NAME => CmpV0::instantiate(
+                &operation,
+                &params,
+                &output_counts,
+                &input_counts,
+                type_store,
+                fun_store,
+            )?,
+            EcdsaVerifyV0::NAME => EcdsaVerifyV0::instantiate(
+                &operation,
+                &params,
+                &output_counts,
+                &input_counts,
+                type_store,
+                fun_store,
+            )?,
             name => bail!("Unsupported plugin: {name}"),
         };
 
@@ -553,14 +702,100 @@ impl FunStore {
             .get(name)
             .ok_or_else(|| eyre!("Missing function name '{name}' in `FuncStore`"))
     }
+
+    /// Return an [`Iterator`] over the name-[`FuncDecl`] pairs in the
+    /// [`FunStore`].
+    pub fn iter(&self) -> std::collections::btree_map::Iter<String, FuncDecl> {
+        self.0.iter()
+    }
 }
 
 // TODO: add type synonym for Vec<u8> serialized field values,
 //       maybe use Box<[u8]> like in other places.
+/// A single `type_id`'s instance/witness stream. [`MemoryTape`] is the
+/// existing fully-buffered path; [`FileTape`] backs the same interface with
+/// a bounded-memory, on-disk stream for tapes too large to hold in RAM.
+trait InputTape: Send {
+    fn len(&self) -> usize;
+    fn pop_front(&mut self) -> Option<Number>;
+    /// Append a value ingested at runtime. Only [`MemoryTape`] supports
+    /// this; a streaming [`FileTape`] is attached read-only up front (see
+    /// [`CircInputs::attach_instance_tapes`]/`attach_witness_tapes`).
+    fn push_back(&mut self, value: Number);
+}
+
+#[derive(Default)]
+struct MemoryTape(VecDeque<Number>);
+
+impl InputTape for MemoryTape {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn pop_front(&mut self) -> Option<Number> {
+        self.0.pop_front()
+    }
+
+    fn push_back(&mut self, value: Number) {
+        self.0.push_back(value);
+    }
+}
+
+/// A per-`type_id` tape backed by a file on disk instead of memory: a
+/// decimal element count on the first line, then one decimal-encoded
+/// [`Number`] per line, read lazily by [`Self::pop_front`] and kept to a
+/// single buffered line at a time rather than loading the whole tape. The
+/// same decimal encoding `Number` already round-trips through elsewhere in
+/// this crate (e.g. plugin width parameters), so no new serialization format
+/// is introduced for it here.
+struct FileTape {
+    reader: std::io::BufReader<std::fs::File>,
+    remaining: usize,
+}
+
+impl FileTape {
+    fn open(path: &std::path::Path) -> std::io::Result<Self> {
+        use std::io::BufRead;
+        let mut reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let remaining = header.trim().parse().map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{}: malformed tape element-count header", path.display()),
+            )
+        })?;
+        Ok(Self { reader, remaining })
+    }
+}
+
+impl InputTape for FileTape {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+
+    fn pop_front(&mut self) -> Option<Number> {
+        use std::io::BufRead;
+        if self.remaining == 0 {
+            return None;
+        }
+        let mut line = String::new();
+        if self.reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        line.trim().parse().ok()
+    }
+
+    fn push_back(&mut self, _value: Number) {
+        panic!("cannot ingest a value into a streaming, file-backed input tape");
+    }
+}
+
 #[derive(Default)]
 pub struct CircInputs {
-    ins: Vec<VecDeque<Number>>,
-    wit: Vec<VecDeque<Number>>,
+    ins: Vec<Box<dyn InputTape>>,
+    wit: Vec<Box<dyn InputTape>>,
 }
 
 impl CircInputs {
@@ -569,7 +804,7 @@ impl CircInputs {
         let n = self.ins.len();
         if n <= type_id {
             for _i in n..(type_id + 1) {
-                self.ins.push(Default::default());
+                self.ins.push(Box::<MemoryTape>::default());
             }
         }
     }
@@ -578,7 +813,7 @@ impl CircInputs {
         let n = self.wit.len();
         if n <= type_id {
             for _i in n..(type_id + 1) {
-                self.wit.push(Default::default());
+                self.wit.push(Box::<MemoryTape>::default());
             }
         }
     }
@@ -608,13 +843,41 @@ impl CircInputs {
     /// Ingest instances.
     pub fn ingest_instances(&mut self, type_id: usize, instances: VecDeque<Number>) {
         self.adjust_ins_type_idx(type_id);
-        self.ins[type_id] = instances;
+        self.ins[type_id] = Box::new(MemoryTape(instances));
     }
 
     /// Ingest witnesses.
     pub fn ingest_witnesses(&mut self, type_id: usize, witnesses: VecDeque<Number>) {
         self.adjust_wit_type_idx(type_id);
-        self.wit[type_id] = witnesses;
+        self.wit[type_id] = Box::new(MemoryTape(witnesses));
+    }
+
+    /// Attach on-disk, lazily-decoded instance tapes for the given
+    /// `type_id`s, replacing whatever (in-memory, possibly empty) tape was
+    /// there before. See [`FileTape`] for the on-disk format. Existing
+    /// `pop_instance`/`num_instances` call sites are unaffected -- they
+    /// don't know or care which backing store they're reading from.
+    pub fn attach_instance_tapes(
+        &mut self,
+        tapes: impl IntoIterator<Item = (usize, std::path::PathBuf)>,
+    ) -> std::io::Result<()> {
+        for (type_id, path) in tapes {
+            self.adjust_ins_type_idx(type_id);
+            self.ins[type_id] = Box::new(FileTape::open(&path)?);
+        }
+        Ok(())
+    }
+
+    /// Witness-side counterpart of [`Self::attach_instance_tapes`].
+    pub fn attach_witness_tapes(
+        &mut self,
+        tapes: impl IntoIterator<Item = (usize, std::path::PathBuf)>,
+    ) -> std::io::Result<()> {
+        for (type_id, path) in tapes {
+            self.adjust_wit_type_idx(type_id);
+            self.wit[type_id] = Box::new(FileTape::open(&path)?);
+        }
+        Ok(())
     }
 
     pub fn pop_instance(&mut self, type_id: usize) -> Option<Number> {
@@ -627,3 +890,378 @@ impl CircInputs {
         self.wit[type_id].pop_front()
     }
 }
+
+/// A structured diagnostic produced by [`validate`], modeled on Zinc's
+/// constant-array diagnostics: each variant carries the concrete offending
+/// value alongside its bound (or name), plus the index of the gate it was
+/// found at, so callers get an actionable message instead of a panic deep
+/// inside a backend.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A `WireRange`'s `last` precedes its `first`.
+    InvalidWireRange {
+        gate_index: usize,
+        first: WireId,
+        last: WireId,
+    },
+    /// A `Conv` gate's source or target [`TypeId`] isn't registered in the
+    /// [`TypeStore`].
+    UnknownConvType { gate_index: usize, type_id: TypeId },
+    /// A `Call` gate names a function that isn't in the [`FunStore`].
+    UnknownFunction { gate_index: usize, name: String },
+    /// A `Call`'s output or input wire ranges don't match the callee's
+    /// declared arity.
+    ArityMismatch {
+        gate_index: usize,
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A gate references a [`TypeId`] that isn't registered in the
+    /// [`TypeStore`] (the generic counterpart of `UnknownConvType`, for
+    /// gates whose single `TypeId` isn't a `Conv`'s).
+    UnknownType { gate_index: usize, type_id: TypeId },
+    /// A gate reads a [`WireId`] that hasn't been written by any earlier
+    /// gate, `New` range, or function argument.
+    UndefinedWire { gate_index: usize, wire: WireId },
+    /// A gate writes a [`WireId`] that some earlier gate (or `New` range)
+    /// already wrote — SSA requires each wire be defined exactly once.
+    RedefinedWire { gate_index: usize, wire: WireId },
+    /// A `Call`'s wire range doesn't carry the same number of wires as the
+    /// callee's declared count for the corresponding [`TypeId`] (arity, i.e.
+    /// the *number* of ranges, can match while the total wire count still
+    /// doesn't).
+    CallWireCountMismatch {
+        gate_index: usize,
+        name: String,
+        type_id: TypeId,
+        expected: WireCount,
+        found: WireCount,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::InvalidWireRange {
+                gate_index,
+                first,
+                last,
+            } => write!(
+                f,
+                "gate {gate_index}: invalid wire range [{first}, {last}] (last < first)"
+            ),
+            ValidationError::UnknownConvType { gate_index, type_id } => write!(
+                f,
+                "gate {gate_index}: `Conv` references unregistered type id {type_id}"
+            ),
+            ValidationError::UnknownFunction { gate_index, name } => write!(
+                f,
+                "gate {gate_index}: `Call` references unknown function '{name}'"
+            ),
+            ValidationError::ArityMismatch {
+                gate_index,
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "gate {gate_index}: call to '{name}' passes {found} wire range(s), expected {expected}"
+            ),
+            ValidationError::UnknownType { gate_index, type_id } => write!(
+                f,
+                "gate {gate_index}: references unregistered type id {type_id}"
+            ),
+            ValidationError::UndefinedWire { gate_index, wire } => write!(
+                f,
+                "gate {gate_index}: reads wire ${wire} before it is defined"
+            ),
+            ValidationError::RedefinedWire { gate_index, wire } => write!(
+                f,
+                "gate {gate_index}: wire ${wire} is defined more than once"
+            ),
+            ValidationError::CallWireCountMismatch {
+                gate_index,
+                name,
+                type_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "gate {gate_index}: call to '{name}' passes {found} wire(s) of type {type_id}, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+fn validate_range(errors: &mut Vec<ValidationError>, gate_index: usize, range: &WireRange) {
+    let (first, last) = *range;
+    if last < first {
+        errors.push(ValidationError::InvalidWireRange {
+            gate_index,
+            first,
+            last,
+        });
+    }
+}
+
+/// Walk `gates`, checking for `WireRange`s with `last < first`, `Conv` gates
+/// whose type ids aren't registered in `type_store`, and `Call` gates that
+/// name an unknown function or pass the wrong number of wire ranges for the
+/// callee's declared arity — all before any of it reaches a backend. Returns
+/// every error found, rather than stopping at the first one, so a caller can
+/// report them all at once.
+pub fn validate(gates: &[GateM], type_store: &TypeStore, fun_store: &FunStore) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (gate_index, gate) in gates.iter().enumerate() {
+        match gate {
+            GateM::Conv(conv) => {
+                let (ty1, range1, ty2, range2) = conv.as_ref();
+                validate_range(&mut errors, gate_index, range1);
+                validate_range(&mut errors, gate_index, range2);
+                if type_store.get(ty1).is_err() {
+                    errors.push(ValidationError::UnknownConvType {
+                        gate_index,
+                        type_id: *ty1,
+                    });
+                }
+                if type_store.get(ty2).is_err() {
+                    errors.push(ValidationError::UnknownConvType {
+                        gate_index,
+                        type_id: *ty2,
+                    });
+                }
+            }
+            GateM::Call(call) => {
+                let (name, out_ranges, in_ranges) = call.as_ref();
+                for range in out_ranges.iter().chain(in_ranges.iter()) {
+                    validate_range(&mut errors, gate_index, range);
+                }
+                match fun_store.get(name) {
+                    Err(_) => errors.push(ValidationError::UnknownFunction {
+                        gate_index,
+                        name: name.clone(),
+                    }),
+                    Ok(func) => {
+                        if out_ranges.len() != func.output_counts().len() {
+                            errors.push(ValidationError::ArityMismatch {
+                                gate_index,
+                                name: name.clone(),
+                                expected: func.output_counts().len(),
+                                found: out_ranges.len(),
+                            });
+                        }
+                        if in_ranges.len() != func.input_counts().len() {
+                            errors.push(ValidationError::ArityMismatch {
+                                gate_index,
+                                name: name.clone(),
+                                expected: func.input_counts().len(),
+                                found: in_ranges.len(),
+                            });
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    errors
+}
+
+fn check_type(
+    errors: &mut Vec<ValidationError>,
+    type_store: &TypeStore,
+    gate_index: usize,
+    ty: TypeId,
+) {
+    if type_store.get(&ty).is_err() {
+        errors.push(ValidationError::UnknownType { gate_index, type_id: ty });
+    }
+}
+
+fn check_read(
+    errors: &mut Vec<ValidationError>,
+    defined: &FxHashSet<WireId>,
+    gate_index: usize,
+    wire: WireId,
+) {
+    if !defined.contains(&wire) {
+        errors.push(ValidationError::UndefinedWire { gate_index, wire });
+    }
+}
+
+fn check_read_range(
+    errors: &mut Vec<ValidationError>,
+    defined: &FxHashSet<WireId>,
+    gate_index: usize,
+    (first, last): WireRange,
+) {
+    if last < first {
+        return; // already flagged by `validate`
+    }
+    for wire in first..=last {
+        check_read(errors, defined, gate_index, wire);
+    }
+}
+
+fn check_define(
+    errors: &mut Vec<ValidationError>,
+    defined: &mut FxHashSet<WireId>,
+    gate_index: usize,
+    wire: WireId,
+) {
+    if !defined.insert(wire) {
+        errors.push(ValidationError::RedefinedWire { gate_index, wire });
+    }
+}
+
+fn check_define_range(
+    errors: &mut Vec<ValidationError>,
+    defined: &mut FxHashSet<WireId>,
+    gate_index: usize,
+    (first, last): WireRange,
+) {
+    if last < first {
+        return; // already flagged by `validate`
+    }
+    for wire in first..=last {
+        check_define(errors, defined, gate_index, wire);
+    }
+}
+
+impl GatesBody {
+    /// Walk this body once, layering SSA-style definedness checks on top of
+    /// [`validate`]'s structural ones: every wire a gate reads must already
+    /// be defined — by an earlier gate's output, a `New` range, or one of
+    /// the wires `defined` is seeded with (e.g. a function's input
+    /// arguments, see [`FuncDecl::verify`]) — and no wire is defined twice.
+    /// `Delete` requires its range be currently defined and then frees it,
+    /// so a later read of the same wires is correctly flagged as
+    /// undefined. Returns every violation found, rather than stopping at
+    /// the first.
+    pub(crate) fn verify(
+        &self,
+        defined: &mut FxHashSet<WireId>,
+        type_store: &TypeStore,
+        fun_store: &FunStore,
+    ) -> Vec<ValidationError> {
+        let mut errors = validate(&self.gates, type_store, fun_store);
+
+        for (gate_index, gate) in self.gates.iter().enumerate() {
+            use GateM::*;
+            match gate {
+                Constant(ty, out, _) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_define(&mut errors, defined, gate_index, *out);
+                }
+                AssertZero(ty, wire) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_read(&mut errors, defined, gate_index, *wire);
+                }
+                Copy(ty, out, inp) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_read(&mut errors, defined, gate_index, *inp);
+                    check_define(&mut errors, defined, gate_index, *out);
+                }
+                Add(ty, out, a, b) | Sub(ty, out, a, b) | Mul(ty, out, a, b) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_read(&mut errors, defined, gate_index, *a);
+                    check_read(&mut errors, defined, gate_index, *b);
+                    check_define(&mut errors, defined, gate_index, *out);
+                }
+                AddConstant(ty, out, inp, _) | MulConstant(ty, out, inp, _) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_read(&mut errors, defined, gate_index, *inp);
+                    check_define(&mut errors, defined, gate_index, *out);
+                }
+                Instance(ty, out) | Witness(ty, out) | Challenge(ty, out) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_define(&mut errors, defined, gate_index, *out);
+                }
+                Conv(conv) => {
+                    let (ty_out, out_range, ty_in, in_range) = conv.as_ref();
+                    check_type(&mut errors, type_store, gate_index, *ty_out);
+                    check_type(&mut errors, type_store, gate_index, *ty_in);
+                    check_read_range(&mut errors, defined, gate_index, *in_range);
+                    check_define_range(&mut errors, defined, gate_index, *out_range);
+                }
+                New(ty, first, last) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_define_range(&mut errors, defined, gate_index, (*first, *last));
+                }
+                Delete(ty, first, last) => {
+                    check_type(&mut errors, type_store, gate_index, *ty);
+                    check_read_range(&mut errors, defined, gate_index, (*first, *last));
+                    if last >= first {
+                        for wire in *first..=*last {
+                            defined.remove(&wire);
+                        }
+                    }
+                }
+                Call(call) => {
+                    let (name, out_ranges, in_ranges) = call.as_ref();
+                    for range in in_ranges {
+                        check_read_range(&mut errors, defined, gate_index, *range);
+                    }
+                    if let Ok(decl) = fun_store.get(name) {
+                        for (counts, ranges) in [
+                            (decl.output_counts(), out_ranges.as_slice()),
+                            (decl.input_counts(), in_ranges.as_slice()),
+                        ] {
+                            for ((ty, count), range) in counts.iter().zip(ranges) {
+                                let (first, last) = *range;
+                                if last < first {
+                                    continue; // already flagged by `validate`
+                                }
+                                let found = last - first + 1;
+                                if found != *count {
+                                    errors.push(ValidationError::CallWireCountMismatch {
+                                        gate_index,
+                                        name: name.clone(),
+                                        type_id: *ty,
+                                        expected: *count,
+                                        found,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    for range in out_ranges {
+                        check_define_range(&mut errors, defined, gate_index, *range);
+                    }
+                }
+                Comment(_) => {}
+            }
+        }
+
+        errors
+    }
+}
+
+impl FuncDecl {
+    /// Run [`GatesBody::verify`] over this function's body, seeding the
+    /// defined-wire set with its input arguments (the output wires are
+    /// *not* seeded — the body itself must define them). A plugin body has
+    /// no internal gate sequence to SSA-check, so this reports no errors
+    /// for one; its wiring is checked where it's instantiated instead.
+    pub(crate) fn verify(&self, type_store: &TypeStore, fun_store: &FunStore) -> Vec<ValidationError> {
+        match &self.body {
+            FunctionBody::Gates(gates) => {
+                let mut defined = FxHashSet::default();
+                let mut wire: WireId = self.output_counts.iter().map(|(_, c)| c).sum();
+                for (_, count) in self.input_counts.iter() {
+                    for w in wire..wire + count {
+                        defined.insert(w);
+                    }
+                    wire += count;
+                }
+                gates.verify(&mut defined, type_store, fun_store)
+            }
+            FunctionBody::Plugin(_) => Vec::new(),
+        }
+    }
+}
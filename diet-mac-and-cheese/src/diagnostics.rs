@@ -0,0 +1,151 @@
+//! Accumulating diagnostics for malformed Circuit IR.
+//!
+//! `TypeStore::get`/`FunStore::get` stay single-shot, fail-fast lookups --
+//! that's the right shape for a point query. This module is for *batch*
+//! passes instead: building a whole [`TypeStore`](crate::circuit_ir::TypeStore)
+//! out of a parsed type list can hit several independent problems (too many
+//! types, a duplicated field, an unsupported field modulus) at once, and a user
+//! debugging a malformed circuit wants all of them in one run rather than
+//! fixing them one `eyre::bail!` at a time.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::circuit_ir::{TypeId, WireId};
+
+/// Where in the source a [`Diagnostic`] was found, when available. Nothing
+/// in this snapshot threads span info out of the parser yet, so every
+/// diagnostic below carries `None` -- this is a hook for once it does.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceSpan {
+    pub line: u64,
+    pub column: u64,
+}
+
+impl Display for SourceSpan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The kind of problem found, plus the concrete offending value -- mirrors
+/// [`circuit_ir::ValidationError`](crate::circuit_ir::ValidationError)'s
+/// shape, for the construction-time problems that pass doesn't cover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// More `@type` entries than the 256 a [`TypeId`] byte can address.
+    TooManyTypes { count: usize, max: usize },
+    /// The same field modulus was declared as more than one `@type`.
+    DuplicateField { type_id: TypeId },
+    /// A field modulus isn't one of the fields this build supports.
+    UnsupportedField { type_id: TypeId, reason: String },
+    /// An extension field `@type` -- not supported at all.
+    UnsupportedExtensionField { type_id: TypeId },
+    /// A `@plugin` name outside the registry `FuncDecl::new_plugin` dispatches to.
+    UnsupportedPlugin { name: String },
+    /// A gate references a [`TypeId`] that was never declared.
+    UnknownType { type_id: TypeId },
+    /// A `Call`/`@function` references a name missing from the `FunStore`.
+    UnknownFunction { name: String, gate_index: usize },
+    /// A `TypeId` is used by a gate but has no output/input wire counts
+    /// declared for it anywhere in scope.
+    MissingWireCounts { type_id: TypeId },
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::TooManyTypes { count, max } => {
+                write!(f, "{count} types declared, but only {max} are addressable")
+            }
+            DiagnosticKind::DuplicateField { type_id } => {
+                write!(f, "type {type_id} redeclares a field modulus already used by an earlier type")
+            }
+            DiagnosticKind::UnsupportedField { type_id, reason } => {
+                write!(f, "type {type_id}: unsupported field modulus ({reason})")
+            }
+            DiagnosticKind::UnsupportedExtensionField { type_id } => {
+                write!(f, "type {type_id}: extension fields are not supported")
+            }
+            DiagnosticKind::UnsupportedPlugin { name } => {
+                write!(f, "unsupported plugin '{name}'")
+            }
+            DiagnosticKind::UnknownType { type_id } => {
+                write!(f, "unknown type {type_id} (no matching `@type` declaration)")
+            }
+            DiagnosticKind::UnknownFunction { name, gate_index } => {
+                write!(f, "unknown function '{name}' referenced at gate {gate_index}")
+            }
+            DiagnosticKind::MissingWireCounts { type_id } => {
+                write!(f, "missing wire counts for type {type_id}")
+            }
+        }
+    }
+}
+
+/// One structured diagnostic: a [`DiagnosticKind`] plus, when known, where
+/// it was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub span: Option<SourceSpan>,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.span {
+            Some(span) => write!(f, "{span}: {}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+/// A batch of [`Diagnostic`]s collected by one pass. Also used as the
+/// `Error` type for the fallible batch conversions it backs (e.g.
+/// `TryFrom<Vec<Type>> for TypeStore`), so `?` still works at a call site
+/// expecting an `eyre::Error` (it implements `From<E: std::error::Error>`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics(Vec<Diagnostic>);
+
+impl Diagnostics {
+    pub fn push(&mut self, kind: DiagnosticKind) {
+        self.0.push(Diagnostic { kind, span: None });
+    }
+
+    pub fn push_at(&mut self, kind: DiagnosticKind, span: SourceSpan) {
+        self.0.push(Diagnostic {
+            kind,
+            span: Some(span),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.0.iter()
+    }
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, diagnostic) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{diagnostic}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+// Not referenced anywhere above; kept so `WireId` stays a live import if
+// a future `MissingWireCounts`-style variant needs to name a specific wire.
+#[allow(dead_code)]
+type _WireIdDocAnchor = WireId;
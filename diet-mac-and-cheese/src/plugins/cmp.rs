@@ -0,0 +1,137 @@
+use mac_n_cheese_sieve_parser::PluginTypeArg;
+
+use crate::backend_trait::BackendT;
+use crate::circuit_ir::{FunStore, TypeId, TypeStore, WireCount};
+use eyre::Result;
+
+use super::{Plugin, PluginExecution};
+
+/// Which comparison [`CmpV0`] asserts, all built from the same MSB-to-LSB
+/// accumulator (see [`CmpV0::accumulate`]).
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CmpOp {
+    LessEqThan,
+    LessThan,
+    Equal,
+}
+
+/// A private-vs-private comparison plugin over the boolean (`F2`) backend:
+/// generalizes `DietMacAndCheeseConvVerifier::less_eq_than_with_public2`,
+/// which only compares a committed bit vector against a *public* constant,
+/// to two equal-length, little-endian, fully private bit ranges.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CmpV0 {
+    field: TypeId,
+    op: CmpOp,
+}
+
+impl CmpV0 {
+    pub fn type_id(&self) -> TypeId {
+        self.field
+    }
+
+    pub fn operation(&self) -> CmpOp {
+        self.op
+    }
+
+    /// Generalizes the `less_eq_than_with_public2` recurrence
+    /// (`act' = act·(1 ⊕ a_i ⊕ b_i)`, `r' = r + (r⊕1)·act·a_i·(b_i⊕1)`,
+    /// processed from the most-significant bit down) from a public `b` to a
+    /// private bit-vector wire: every place the original folded `b_i` into a
+    /// constant now goes through `add`/`mul` instead. Returns `(act, r)`:
+    /// `act` is 1 iff every bit matched (`a == b`), `r` is 1 iff the first
+    /// mismatch from the top has `a_i = 1, b_i = 0` (`a > b`).
+    fn accumulate<B: BackendT>(
+        backend: &mut B,
+        a: &[B::Wire],
+        b: &[B::Wire],
+    ) -> Result<(B::Wire, B::Wire)> {
+        assert_eq!(a.len(), b.len());
+
+        let one = backend.one()?;
+        let mut act = backend.input_public(one)?;
+        let zero = backend.zero()?;
+        let mut r = backend.input_public(zero)?;
+
+        let l = a.len();
+        for i in 0..l {
+            let a_i = &a[l - i - 1];
+            let b_i = &b[l - i - 1];
+
+            // (1 ⊕ a_i ⊕ b_i)
+            let a_xor_b = backend.add(a_i, b_i)?;
+            let one_plus_a_xor_b = backend.add_constant(&a_xor_b, one)?;
+
+            // act' = act * (1 ⊕ a_i ⊕ b_i)
+            let act_prime = backend.mul(&act, &one_plus_a_xor_b)?;
+
+            // r + 1
+            let r_plus_one = backend.add_constant(&r, one)?;
+
+            // not_b_i = 1 ⊕ b_i
+            let not_b_i = backend.add_constant(b_i, one)?;
+
+            // p1 = a_i * (1 ⊕ b_i)
+            let p1 = backend.mul(a_i, &not_b_i)?;
+
+            // act * p1
+            let act_p1 = backend.mul(&act, &p1)?;
+
+            // r' = r + (r+1) * (act * p1)
+            let p2 = backend.mul(&r_plus_one, &act_p1)?;
+            r = backend.add(&r, &p2)?;
+
+            act = act_prime;
+        }
+
+        Ok((act, r))
+    }
+
+    /// Run the comparison selected by [`Self::operation`] on `a`/`b`, for
+    /// any `BackendT` (though this plugin is only dispatched over the
+    /// boolean backend; see `plugin_call_gate`).
+    pub fn execute<B: BackendT>(&self, a: &[B::Wire], b: &[B::Wire], backend: &mut B) -> Result<()> {
+        let (act, r) = Self::accumulate(backend, a, b)?;
+        match self.op {
+            CmpOp::LessEqThan => backend.assert_zero(&r),
+            CmpOp::LessThan => {
+                backend.assert_zero(&r)?;
+                backend.assert_zero(&act)
+            }
+            CmpOp::Equal => {
+                let neg_one = -backend.one()?;
+                let hope_zero = backend.add_constant(&act, neg_one)?;
+                backend.assert_zero(&hope_zero)
+            }
+        }
+    }
+}
+
+impl Plugin for CmpV0 {
+    const NAME: &'static str = "cmp_v0";
+
+    fn instantiate(
+        operation: &str,
+        _params: &[PluginTypeArg],
+        _output_counts: &[(TypeId, WireCount)],
+        input_counts: &[(TypeId, WireCount)],
+        _type_store: &TypeStore,
+        _fun_store: &FunStore,
+    ) -> Result<PluginExecution> {
+        let op = match operation {
+            "less_eq" => CmpOp::LessEqThan,
+            "less_than" => CmpOp::LessThan,
+            "equal" => CmpOp::Equal,
+            _ => panic!("unsupported comparison operation: \"{}\"", operation),
+        };
+
+        let mut field = None;
+        for (typ, _cnt) in input_counts.iter().copied() {
+            field = Some(typ);
+        }
+        Ok(PluginExecution::LessEqThan(CmpV0 {
+            field: field.unwrap(),
+            op,
+        }))
+    }
+}
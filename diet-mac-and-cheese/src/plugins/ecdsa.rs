@@ -0,0 +1,89 @@
+use mac_n_cheese_sieve_parser::PluginTypeArg;
+
+use crate::backend_multifield::ecdsa::precompute_g_table;
+use crate::circuit_ir::{FunStore, TypeId, TypeSpecification, TypeStore, WireCount};
+use eyre::{bail, ensure, Result};
+use swanky_field_ff_primes::{Secp256k1, Secp256k1order};
+
+use super::{Plugin, PluginExecution};
+
+/// The bit length used for the fixed-base table and the scalar-multiplication
+/// ladders: secp256k1's base field and group order are both just under
+/// 2^256, so 256 steps cover either with room to spare.
+const LADDER_BITS: usize = 256;
+
+/// Proves knowledge of a valid ECDSA-over-secp256k1 signature `(r, s)` on
+/// message hash `e` under public key `Q`, without the caller having to
+/// hand-write the point-arithmetic gates themselves. See
+/// `EvaluatorCirc::eval_ecdsa_verify` for the actual verification (it needs
+/// both the `Secp256k1` and `Secp256k1order` backends at once, so it isn't
+/// dispatched through the single-field `plugin_call_gate` like the other
+/// plugins in this module).
+///
+/// Inputs, in order: `Qx, Qy` (2 wires of the `Secp256k1` field), then
+/// `e, r, s` (3 wires of the `Secp256k1order` field). No outputs — the call
+/// fails (via `assert_zero` on a non-zero value) if the signature doesn't
+/// verify.
+pub(crate) struct EcdsaVerifyV0 {
+    fp_field: TypeId,
+    fn_field: TypeId,
+    g_table: Vec<(Secp256k1, Secp256k1)>,
+}
+
+impl EcdsaVerifyV0 {
+    pub fn fp_field(&self) -> TypeId {
+        self.fp_field
+    }
+
+    pub fn fn_field(&self) -> TypeId {
+        self.fn_field
+    }
+
+    pub fn g_table(&self) -> &[(Secp256k1, Secp256k1)] {
+        &self.g_table
+    }
+}
+
+impl Plugin for EcdsaVerifyV0 {
+    const NAME: &'static str = "ecdsa_verify_v0";
+
+    fn instantiate(
+        _operation: &str,
+        _params: &[PluginTypeArg],
+        output_counts: &[(TypeId, WireCount)],
+        input_counts: &[(TypeId, WireCount)],
+        type_store: &TypeStore,
+        _fun_store: &FunStore,
+    ) -> Result<PluginExecution> {
+        ensure!(
+            output_counts.is_empty(),
+            "ecdsa_verify_v0 takes no outputs, only an implicit pass/fail assertion"
+        );
+        ensure!(
+            input_counts.len() == 2,
+            "ecdsa_verify_v0 expects exactly two input ranges: (Qx,Qy) then (e,r,s)"
+        );
+        let (fp_field, fp_count) = input_counts[0];
+        let (fn_field, fn_count) = input_counts[1];
+        ensure!(fp_count == 2, "expected 2 base-field wires (Qx, Qy)");
+        ensure!(fn_count == 3, "expected 3 scalar-field wires (e, r, s)");
+
+        // Gate the whole plugin behind field availability: both secp256k1
+        // fields must actually have been declared (and so will be loaded as
+        // backends) for this plugin to mean anything.
+        match type_store.get(&fp_field)? {
+            TypeSpecification::Field(f) if *f == std::any::TypeId::of::<Secp256k1>() => {}
+            _ => bail!("ecdsa_verify_v0's first input type must be the Secp256k1 field"),
+        }
+        match type_store.get(&fn_field)? {
+            TypeSpecification::Field(f) if *f == std::any::TypeId::of::<Secp256k1order>() => {}
+            _ => bail!("ecdsa_verify_v0's second input type must be the Secp256k1order field"),
+        }
+
+        Ok(PluginExecution::EcdsaVerify(EcdsaVerifyV0 {
+            fp_field,
+            fn_field,
+            g_table: precompute_g_table(LADDER_BITS),
+        }))
+    }
+}
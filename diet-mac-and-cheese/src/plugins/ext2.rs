@@ -0,0 +1,107 @@
+//! A degree-2 extension-field accumulator, shared by [`super::lookup::LookupV0`]
+//! (and meant to be reused by `PermutationCheck`-style plugins): a single
+//! random challenge over a small base field like `F61p` gives a grand-product
+//! or fractional-sum check soundness error on the order of `N/2^61`, which is
+//! too weak for large instances. Running the accumulation in `GF(p^2)`
+//! instead — represented as a pair of base-field wires `(x0, x1)` meaning
+//! `x0 + x1·u` with `u^2 = β` for a fixed non-residue `β` — squares the field
+//! size and lifts soundness to `N/2^122`, without changing the VOLE backend
+//! underneath.
+
+use eyre::Result;
+
+use crate::backend_trait::BackendT;
+
+/// An extension-field element `x0 + x1·u`, as a pair of base-field wires.
+#[derive(Clone, Copy)]
+pub(crate) struct Ext2<W> {
+    pub x0: W,
+    pub x1: W,
+}
+
+/// Lift a base-field wire `w` to the extension element `w + 0·u`.
+pub(crate) fn from_base<B: BackendT>(backend: &mut B, w: &B::Wire) -> Result<Ext2<B::Wire>> {
+    let x1 = backend.input_public(backend.zero()?)?;
+    Ok(Ext2 {
+        x0: backend.copy(w)?,
+        x1,
+    })
+}
+
+/// Sample the extension challenge as two independent base-field randoms.
+pub(crate) fn challenge<B: BackendT>(backend: &mut B) -> Result<Ext2<B::Wire>> {
+    let c0 = backend.random()?;
+    let x0 = backend.input_public(c0)?;
+    let c1 = backend.random()?;
+    let x1 = backend.input_public(c1)?;
+    Ok(Ext2 { x0, x1 })
+}
+
+pub(crate) fn add<B: BackendT>(
+    backend: &mut B,
+    a: &Ext2<B::Wire>,
+    b: &Ext2<B::Wire>,
+) -> Result<Ext2<B::Wire>> {
+    Ok(Ext2 {
+        x0: backend.add(&a.x0, &b.x0)?,
+        x1: backend.add(&a.x1, &b.x1)?,
+    })
+}
+
+pub(crate) fn sub<B: BackendT>(
+    backend: &mut B,
+    a: &Ext2<B::Wire>,
+    b: &Ext2<B::Wire>,
+) -> Result<Ext2<B::Wire>> {
+    Ok(Ext2 {
+        x0: backend.sub(&a.x0, &b.x0)?,
+        x1: backend.sub(&a.x1, &b.x1)?,
+    })
+}
+
+/// Extension multiplication via Karatsuba: three base `mul`s (`a0·b0`,
+/// `a1·b1`, `(a0+a1)·(b0+b1)`) plus the `β`-fold, instead of the naive four.
+pub(crate) fn mul<B: BackendT>(
+    backend: &mut B,
+    a: &Ext2<B::Wire>,
+    b: &Ext2<B::Wire>,
+    beta: B::FieldElement,
+) -> Result<Ext2<B::Wire>> {
+    let p0 = backend.mul(&a.x0, &b.x0)?;
+    let p1 = backend.mul(&a.x1, &b.x1)?;
+    let a_sum = backend.add(&a.x0, &a.x1)?;
+    let b_sum = backend.add(&b.x0, &b.x1)?;
+    let p2 = backend.mul(&a_sum, &b_sum)?;
+    let beta_p1 = backend.mul_constant(&p1, beta)?;
+    let x0 = backend.add(&p0, &beta_p1)?;
+    let x1 = backend.sub(&p2, &p0)?;
+    let x1 = backend.sub(&x1, &p1)?;
+    Ok(Ext2 { x0, x1 })
+}
+
+pub(crate) fn assert_zero<B: BackendT>(backend: &mut B, a: &Ext2<B::Wire>) -> Result<()> {
+    backend.assert_zero(&a.x0)?;
+    backend.assert_zero(&a.x1)
+}
+
+/// The extension-field additive identity.
+pub(crate) fn zero<B: BackendT>(backend: &mut B) -> Result<Ext2<B::Wire>> {
+    let z = backend.zero()?;
+    let x0 = backend.input_public(z)?;
+    let x1 = backend.input_public(z)?;
+    Ok(Ext2 { x0, x1 })
+}
+
+/// Multiply an extension element by a base-field wire (not a full extension
+/// element): `(x0, x1) * s = (x0·s, x1·s)`. Cheaper than [`mul`] when one
+/// factor, like a lookup multiplicity, is known to live in the base field.
+pub(crate) fn scale<B: BackendT>(
+    backend: &mut B,
+    a: &Ext2<B::Wire>,
+    s: &B::Wire,
+) -> Result<Ext2<B::Wire>> {
+    Ok(Ext2 {
+        x0: backend.mul(&a.x0, s)?,
+        x1: backend.mul(&a.x1, s)?,
+    })
+}
@@ -0,0 +1,74 @@
+use mac_n_cheese_sieve_parser::{Number, PluginTypeArg};
+
+use crate::circuit_ir::{FunStore, TypeId, TypeStore, WireCount};
+use eyre::Result;
+
+use super::{Plugin, PluginExecution};
+
+/// A decomposable (Lasso-style) lookup: rather than proving membership in
+/// one table the size of the whole range, the input is split into
+/// `SIZE_DIM`-many narrow digits (one per input wire), each looked up
+/// against its own `2^width`-entry identity sub-table via the
+/// `ram::Prover`/`Verifier` permutation check (see `ram/mod.rs`), and the
+/// digit values recombined with a weighted sum in `base`. This reuses the
+/// same offline memory-checking machinery `galois_ram_v0` is built on,
+/// rather than `lookup_v0`'s logUp identity, since here the table is
+/// structural (every digit's identity table) rather than arbitrary data.
+#[derive(Debug, Clone)]
+pub(crate) struct GaloisLookupV0 {
+    field: TypeId,
+    width: usize,
+    base: Number,
+}
+
+impl GaloisLookupV0 {
+    pub fn field(&self) -> TypeId {
+        self.field
+    }
+
+    /// Number of bits covered by each digit's sub-table (`2^width` entries).
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Collation base: digit `i`'s looked-up value is weighted by `base^i`
+    /// when recombined into the output.
+    pub fn base(&self) -> &Number {
+        &self.base
+    }
+}
+
+impl Plugin for GaloisLookupV0 {
+    const NAME: &'static str = "galois_lookup_v0";
+
+    fn instantiate(
+        operation: &str,
+        params: &[PluginTypeArg],
+        _output_counts: &[(TypeId, WireCount)],
+        input_counts: &[(TypeId, WireCount)],
+        _type_store: &TypeStore,
+        _fun_store: &FunStore,
+    ) -> Result<PluginExecution> {
+        let width = match operation.split_once(':') {
+            Some(("range", width)) => width
+                .parse::<usize>()
+                .unwrap_or_else(|_| panic!("invalid chunk width in \"{}\"", operation)),
+            _ => panic!("unsupported galois lookup operation: \"{}\"", operation),
+        };
+
+        let base = match params.first() {
+            Some(PluginTypeArg::Number(base)) => base.clone(),
+            _ => panic!("galois_lookup_v0 requires a collation base as its first param"),
+        };
+
+        let mut field = None;
+        for (typ, _cnt) in input_counts.iter().copied() {
+            field = Some(typ);
+        }
+        Ok(PluginExecution::GaloisLookup(GaloisLookupV0 {
+            field: field.unwrap(),
+            width,
+            base,
+        }))
+    }
+}
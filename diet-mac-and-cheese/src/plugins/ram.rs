@@ -1,6 +1,7 @@
-use mac_n_cheese_sieve_parser::PluginTypeArg;
+use mac_n_cheese_sieve_parser::{Number, PluginTypeArg};
 
 use crate::circuit_ir::{FunStore, TypeId, TypeStore, WireCount};
+use crate::ram;
 use eyre::Result;
 
 use super::{Plugin, PluginExecution};
@@ -9,12 +10,25 @@ use super::{Plugin, PluginExecution};
 pub enum RamOperation {
     Read,
     Write,
+    /// A read against a preinitialized, write-free memory (see
+    /// `ram::RomProver`/`RomVerifier`): distinct from `Read` so that a
+    /// circuit built against a `"read_only"` instance has no `Write` arm
+    /// to dispatch to in the first place.
+    ReadOnly,
 }
 
+/// A configured `galois_ram_v0` instance: besides the field and the
+/// operation, this carries the address/value widths (in field elements)
+/// the circuit asked for, read from `params` at [`Self::instantiate`]
+/// time, since the backend's `ram::MemoryProver`/`MemoryVerifier` must be
+/// monomorphized to a single `(SIZE_ADDR, SIZE_VALUE)` pair ahead of time
+/// (see `ram::SUPPORTED_SIZES`).
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct RamV0 {
     field: TypeId,
     op: RamOperation,
+    addr_width: usize,
+    value_width: usize,
 }
 
 impl RamV0 {
@@ -25,6 +39,22 @@ impl RamV0 {
     pub fn operation(&self) -> RamOperation {
         self.op
     }
+
+    pub fn addr_width(&self) -> usize {
+        self.addr_width
+    }
+
+    pub fn value_width(&self) -> usize {
+        self.value_width
+    }
+}
+
+/// `params` carry widths as decimal `Number`s rather than native integers,
+/// so go through `Display` to get a `usize` out of one.
+fn width_param(n: &Number) -> usize {
+    format!("{n}")
+        .parse()
+        .unwrap_or_else(|_| panic!("galois_ram_v0: width parameter \"{n}\" does not fit in a usize"))
 }
 
 impl Plugin for RamV0 {
@@ -32,8 +62,8 @@ impl Plugin for RamV0 {
 
     fn instantiate(
         operation: &str,
-        _params: &[PluginTypeArg],
-        _output_counts: &[(TypeId, WireCount)],
+        params: &[PluginTypeArg],
+        output_counts: &[(TypeId, WireCount)],
         input_counts: &[(TypeId, WireCount)],
         _type_store: &TypeStore,
         _fun_store: &FunStore,
@@ -41,9 +71,46 @@ impl Plugin for RamV0 {
         let op = match operation {
             "read" => RamOperation::Read,
             "write" => RamOperation::Write,
+            "read_only" => RamOperation::ReadOnly,
             _ => panic!("unsupported memory operation: \"{}\"", operation),
         };
 
+        // address width, then value width; both default to a scalar
+        // element when omitted, matching the old hardcoded behavior.
+        let mut widths = params.iter().map(|arg| match arg {
+            PluginTypeArg::Number(n) => width_param(n),
+            _ => panic!("galois_ram_v0 params must be numeric widths"),
+        });
+        let addr_width = widths.next().unwrap_or(1);
+        let value_width = widths.next().unwrap_or(1);
+        assert!(
+            ram::SUPPORTED_SIZES.contains(&(addr_width, value_width)),
+            "galois_ram_v0: unsupported (addr_width={}, value_width={}); supported pairs: {:?}",
+            addr_width,
+            value_width,
+            ram::SUPPORTED_SIZES,
+        );
+
+        let declared_inputs: usize = input_counts.iter().map(|(_, cnt)| *cnt as usize).sum();
+        let expected_inputs = match op {
+            RamOperation::Read | RamOperation::ReadOnly => addr_width,
+            RamOperation::Write => addr_width + value_width,
+        };
+        assert_eq!(
+            declared_inputs, expected_inputs,
+            "galois_ram_v0::{}: expected {} input wire(s) for (addr_width={}, value_width={}), circuit declares {}",
+            operation, expected_inputs, addr_width, value_width, declared_inputs,
+        );
+
+        if !matches!(op, RamOperation::Write) {
+            let declared_outputs: usize = output_counts.iter().map(|(_, cnt)| *cnt as usize).sum();
+            assert_eq!(
+                declared_outputs, value_width,
+                "galois_ram_v0::{}: expected {} output wire(s) for value_width={}, circuit declares {}",
+                operation, value_width, value_width, declared_outputs,
+            );
+        }
+
         let mut field = None;
         for (typ, cnt) in input_counts.into_iter().copied() {
             field = Some(typ);
@@ -51,6 +118,8 @@ impl Plugin for RamV0 {
         Ok(PluginExecution::Ram(RamV0 {
             field: field.unwrap(),
             op,
+            addr_width,
+            value_width,
         }))
     }
 }
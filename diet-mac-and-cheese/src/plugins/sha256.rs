@@ -0,0 +1,63 @@
+use mac_n_cheese_sieve_parser::PluginTypeArg;
+
+use crate::backend_multifield::sha256::compress;
+use crate::backend_trait::BackendT;
+use crate::circuit_ir::{FunStore, TypeId, TypeStore, WireCount};
+use eyre::Result;
+
+use super::{Plugin, PluginExecution};
+
+/// A reusable SHA-256 compression-function gadget, so that boolean (`F2`)
+/// circuits don't have to hand-build the ~127000 XOR/AND gates of one
+/// compression call themselves. Takes 512 message-bit wires plus the
+/// current 256-bit chaining state and produces the 256-bit updated state,
+/// using the same little-endian, per-32-bit-word wire layout as
+/// [`crate::backend_multifield::sha256::compress`], which does the actual
+/// work: XOR is `add`, AND is `mul`, NOT is `add_constant` by one, and
+/// rotations/shifts are pure wire reindexing.
+///
+/// Only meaningful over the boolean backend: `plugin_call_gate` rejects this
+/// plugin when `is_boolean` is false.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Sha256V0 {
+    field: TypeId,
+}
+
+impl Sha256V0 {
+    pub fn type_id(&self) -> TypeId {
+        self.field
+    }
+
+    pub fn execute<B: BackendT>(
+        &self,
+        message: &[B::Wire],
+        state: &[B::Wire],
+        backend: &mut B,
+    ) -> Result<Vec<B::Wire>>
+    where
+        B::Wire: Clone,
+    {
+        compress(backend, message, state)
+    }
+}
+
+impl Plugin for Sha256V0 {
+    const NAME: &'static str = "sha256_v0";
+
+    fn instantiate(
+        _operation: &str,
+        _params: &[PluginTypeArg],
+        _output_counts: &[(TypeId, WireCount)],
+        input_counts: &[(TypeId, WireCount)],
+        _type_store: &TypeStore,
+        _fun_store: &FunStore,
+    ) -> Result<PluginExecution> {
+        let mut field = None;
+        for (typ, _cnt) in input_counts.iter().copied() {
+            field = Some(typ);
+        }
+        Ok(PluginExecution::Sha256(Sha256V0 {
+            field: field.unwrap(),
+        }))
+    }
+}
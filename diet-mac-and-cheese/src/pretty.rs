@@ -0,0 +1,175 @@
+//! A textual emitter for Circuit IR — the inverse of parsing.
+//!
+//! [`GateM`] and [`GatesBody`] implement [`Display`], rendering the
+//! canonical SIEVE IR gate syntax the parser accepts; [`TypeStore`] and
+//! [`FunStore`] do the same for `@type`/`@function` declarations. Together
+//! these let a circuit round-trip back to text (`parse(emit(circuit))
+//! == circuit`), which is mainly useful for inspecting a circuit after a
+//! lowering/transformation pass and for golden round-trip tests. Plugins
+//! do not round-trip: a plugin `@type` is emitted in its `Debug` form, and
+//! a plugin function's body is emitted without its static parameters.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::circuit_ir::{
+    FunStore, FuncDecl, FunctionBody, GateM, GatesBody, TypeId, TypeSpecification, TypeStore,
+    WireCount, WireRange,
+};
+use crate::fields::type_id_to_modulus;
+
+fn fmt_range(f: &mut Formatter<'_>, (first, last): WireRange) -> fmt::Result {
+    if first == last {
+        write!(f, "${first}")
+    } else {
+        write!(f, "${first} ... ${last}")
+    }
+}
+
+impl Display for GateM {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use GateM::*;
+        match self {
+            Constant(ty, out, val) => write!(f, "${out} <- @constant({ty}: <{val}>);"),
+            AssertZero(ty, wire) => write!(f, "@assert_zero({ty}: ${wire});"),
+            Copy(ty, out, inp) => write!(f, "${out} <- @copy({ty}: ${inp});"),
+            Add(ty, out, a, b) => write!(f, "${out} <- @add({ty}: ${a}, ${b});"),
+            Sub(ty, out, a, b) => write!(f, "${out} <- @sub({ty}: ${a}, ${b});"),
+            Mul(ty, out, a, b) => write!(f, "${out} <- @mul({ty}: ${a}, ${b});"),
+            AddConstant(ty, out, inp, val) => write!(f, "${out} <- @addc({ty}: ${inp}, <{val}>);"),
+            MulConstant(ty, out, inp, val) => write!(f, "${out} <- @mulc({ty}: ${inp}, <{val}>);"),
+            Instance(ty, out) => write!(f, "${out} <- @public({ty});"),
+            Witness(ty, out) => write!(f, "${out} <- @private({ty});"),
+            Conv(conv) => {
+                let (ty_out, out, ty_in, inp) = conv.as_ref();
+                write!(f, "@convert({ty_out}: ")?;
+                fmt_range(f, *out)?;
+                write!(f, ", {ty_in}: ")?;
+                fmt_range(f, *inp)?;
+                write!(f, ");")
+            }
+            New(ty, first, last) => write!(f, "@new({ty}: ${first} ... ${last});"),
+            Delete(ty, first, last) => write!(f, "@delete({ty}: ${first} ... ${last});"),
+            Call(call) => {
+                let (name, outs, ins) = call.as_ref();
+                if !outs.is_empty() {
+                    for (i, range) in outs.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        fmt_range(f, *range)?;
+                    }
+                    write!(f, " <- ")?;
+                }
+                write!(f, "@call({name}")?;
+                for range in ins {
+                    write!(f, ", ")?;
+                    fmt_range(f, *range)?;
+                }
+                write!(f, ");")
+            }
+            Challenge(ty, out) => write!(f, "${out} <- @challenge({ty});"),
+            Comment(text) => write!(f, "// {text}"),
+        }
+    }
+}
+
+impl Display for GatesBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for gate in self.gates() {
+            writeln!(f, "  {gate}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for TypeSpecification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeSpecification::Field(type_id) => {
+                write!(f, "@type field {};", type_id_to_modulus(*type_id))
+            }
+            // `PluginType` doesn't carry enough of the original `@type
+            // @plugin(...)` declaration back out to re-emit it verbatim, so
+            // this isn't round-trippable yet -- `Debug` at least keeps it
+            // visible in the emitted text rather than silently dropping it.
+            TypeSpecification::Plugin(plugin_type) => {
+                write!(f, "@type {plugin_type:?}; // plugin type: round-trip unsupported")
+            }
+        }
+    }
+}
+
+impl Display for TypeStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (_id, spec) in self.iter() {
+            writeln!(f, "{spec}")?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_arg_counts(f: &mut Formatter<'_>, label: &str, counts: &[(TypeId, WireCount)]) -> fmt::Result {
+    if counts.is_empty() {
+        return Ok(());
+    }
+    write!(f, ", {label}: ")?;
+    for (i, (ty, count)) in counts.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{ty}:{count}")?;
+    }
+    Ok(())
+}
+
+/// Renders one `@function` declaration. [`FuncDecl`] itself has no name (a
+/// [`FunStore`] keys it by name), so pair the two up here rather than
+/// implementing `Display` on `FuncDecl` directly.
+pub(crate) struct FuncDeclIr<'a> {
+    name: &'a str,
+    decl: &'a FuncDecl,
+}
+
+impl FuncDecl {
+    /// Render this declaration's `@function` block under the given name.
+    pub(crate) fn display<'a>(&'a self, name: &'a str) -> FuncDeclIr<'a> {
+        FuncDeclIr { name, decl: self }
+    }
+}
+
+impl Display for FuncDeclIr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@function({}", self.name)?;
+        fmt_arg_counts(f, "@out", &self.decl.output_counts)?;
+        fmt_arg_counts(f, "@in", &self.decl.input_counts)?;
+        writeln!(f, ")")?;
+        match self.decl.body() {
+            FunctionBody::Gates(gates) => write!(f, "{gates}")?,
+            FunctionBody::Plugin(body) => {
+                writeln!(f, "  @plugin({}, {});", body.name(), body.operation())?;
+                // Static plugin params (e.g. a lookup's table width, a RAM's
+                // address/value widths) aren't retained past `instantiate`
+                // on `PluginExecution`, so they can't be re-emitted here.
+            }
+        }
+        writeln!(f, "@end")
+    }
+}
+
+impl Display for FunStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (name, decl) in self.iter() {
+            write!(f, "{}", decl.display(name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialize a full circuit's relation (not its instances/witnesses) back to
+/// SIEVE IR text: `@type` declarations, every `@function` in `fun_store`,
+/// then `main`'s gates wrapped in `@begin`/`@end`.
+pub fn emit_circuit(type_store: &TypeStore, fun_store: &FunStore, main: &GatesBody) -> String {
+    format!(
+        "version 2.0.0;\ncircuit;\n{type_store}{fun_store}@begin\n{main}@end\n"
+    )
+}
@@ -12,12 +12,22 @@ use crate::{
 
 mod perm;
 mod prover;
+mod rom;
 mod tests;
+mod transport;
 mod tx;
 mod verifier;
+mod vm;
 
 pub use prover::Prover;
+pub use rom::{RomMemoryProver, RomMemoryVerifier, RomProver, RomVerifier};
+#[cfg(feature = "tokio")]
+pub use transport::AsyncTransportBridge;
+#[cfg(feature = "tokio")]
+pub use transport::AsyncRamTransport;
+pub use transport::RamTransport;
 pub use verifier::Verifier;
+pub use vm::{encode_instruction, Opcode, VmProver, VmVerifier, OPCODES, RADIX as VM_RADIX};
 
 const SEP: &[u8] = b"FS_RAM";
 
@@ -30,6 +40,74 @@ const RAM_STEPS: usize = 1 << 24;
 const PRE_ALLOC_MEM: usize = RAM_SIZE;
 const PRE_ALLOC_STEPS: usize = RAM_STEPS + RAM_SIZE;
 
+// Width of the timestamp-ordering range check below. `assert_ts_lt` proves
+// `acc == diff` as a *field* equation over `TS_BITS` committed bits, which
+// only implies `diff == current_ts - stored_ts - 1` as *integers* so long as
+// `acc`'s range `[0, 2^TS_BITS)` can't wrap the field's modulus -- otherwise
+// a malicious prover can pick bits that sum to `diff + k*p` for some `k` and
+// sail through. This used to be a flat 64, which is unsound for the
+// smallest field this crate ever instantiates RAM over (`F61p`, modulus
+// `2^61 - 1`): 64 bits overflow it. 48 bits keeps `2^TS_BITS` comfortably
+// under every supported field's modulus while still covering `RAM_STEPS`
+// accesses many times over.
+pub(super) const TS_BITS: usize = 48;
+const _: () = assert!(
+    TS_BITS < 61 && (1usize << TS_BITS) > RAM_STEPS,
+    "TS_BITS must stay below the smallest field modulus this crate uses for RAM (F61p, 2^61 - 1) while still covering RAM_STEPS accesses"
+);
+
+/// Assert `stored_ts < current_ts`, both native step counters, where
+/// `stored_ts` only arrives committed (as `stored_ts_wire`, valued
+/// `current_ts_elem`'s field whenever the caller knows it, i.e. the
+/// prover) and `current_ts` is public (both parties run the same
+/// deterministic sequence of accesses, so the step count at any point in
+/// the trace is known to the verifier too).
+///
+/// Bit-decomposes `diff = current_ts - stored_ts - 1` (which is `>= 0` iff
+/// `stored_ts < current_ts`) into [`TS_BITS`] committed boolean wires and
+/// checks their weighted sum reconstructs `diff`; the prover derives the
+/// witness bits from `stored_ts_native`, the verifier commits opaque bits
+/// (`stored_ts_native: None`).
+pub(super) fn assert_ts_lt<B: BackendT>(
+    backend: &mut B,
+    stored_ts_wire: &B::Wire,
+    stored_ts_native: Option<u64>,
+    current_ts_native: u64,
+    current_ts_elem: B::FieldElement,
+) -> Result<()> {
+    assert!(
+        stored_ts_native.map_or(true, |t| t < current_ts_native),
+        "a stored timestamp must always precede the access that reads it"
+    );
+    let diff_native = stored_ts_native.map(|t| current_ts_native - t - 1);
+
+    let mut acc = backend.constant(B::FieldElement::ZERO)?;
+    let mut pow = B::FieldElement::ONE;
+    for i in 0..TS_BITS {
+        let bit_val = diff_native.map(|d| {
+            if (d >> i) & 1 == 1 {
+                B::FieldElement::ONE
+            } else {
+                B::FieldElement::ZERO
+            }
+        });
+        let bit = backend.input_private(bit_val)?;
+        let bit_minus_one = backend.add_constant(&bit, -B::FieldElement::ONE)?;
+        let boolean_check = backend.mul(&bit, &bit_minus_one)?;
+        backend.assert_zero(&boolean_check)?;
+
+        let term = backend.mul_constant(&bit, pow)?;
+        acc = backend.add(&acc, &term)?;
+        pow += pow;
+    }
+
+    // acc =?= current_ts - stored_ts - 1
+    let neg_stored = backend.mul_constant(stored_ts_wire, -B::FieldElement::ONE)?;
+    let rhs = backend.add_constant(&neg_stored, current_ts_elem - B::FieldElement::ONE)?;
+    let check = backend.sub(&acc, &rhs)?;
+    backend.assert_zero(&check)
+}
+
 pub fn combine<'a, B: BackendT>(
     backend: &'a mut B,
     mut elems: impl Iterator<Item = &'a B::Wire>,
@@ -120,11 +198,102 @@ impl<F: FiniteField> MemorySpace<F> for Bounded<F> {
     }
 }
 
+/// `(SIZE_ADDR, SIZE_VALUE)` pairs `galois_ram_v0` can be configured with
+/// (see `plugins/ram.rs`'s `RamV0::instantiate`, which validates a
+/// circuit's requested widths against this table up front). Each pair
+/// needs its own monomorphized `Prover`/`Verifier`, so the table — and the
+/// `prover_kind!`/`verifier_kind!`-generated dispatch below — is
+/// necessarily finite; widening RAM support to a new shape just means
+/// adding another entry (and a matching arm in both macro invocations).
+pub(crate) const SUPPORTED_SIZES: &[(usize, usize)] = &[(1, 1), (2, 1), (1, 2), (2, 2), (4, 4)];
+
+/// Generates a `ProverKind` enum with one variant per supported
+/// `(SIZE_ADDR, SIZE_VALUE)` pair, plus the `new`/`read`/`write`/`finalize`
+/// dispatch that picks the right variant. `SIZE_DIM` is spelled out
+/// alongside each pair (rather than computed as `SIZE_ADDR + SIZE_VALUE +
+/// SIZE_CHAL`) since const-generic arithmetic in this position isn't
+/// stable Rust.
+macro_rules! prover_kind {
+    ($( $variant:ident : ($addr:literal, $value:literal, $dim:literal) ),+ $(,)?) => {
+        enum ProverKind<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            $( $variant(Prover<V, F, C, Bounded<V>, $addr, $value, 3, 2, $dim>) ),+
+        }
+
+        impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> ProverKind<V, F, C>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            fn new(
+                dmc: &mut DietMacAndCheeseProver<V, F, C>,
+                addr_width: usize,
+                value_width: usize,
+                bound: usize,
+            ) -> Self {
+                match (addr_width, value_width) {
+                    $( ($addr, $value) => ProverKind::$variant(Prover::new(dmc, Bounded::new(bound))), )+
+                    (a, v) => panic!(
+                        "unsupported RAM (addr_width={a}, value_width={v}); supported: {SUPPORTED_SIZES:?}"
+                    ),
+                }
+            }
+
+            fn read(
+                &mut self,
+                dmc: &mut DietMacAndCheeseProver<V, F, C>,
+                addr: &[MacProver<V, F>],
+            ) -> Result<Vec<MacProver<V, F>>> {
+                match self {
+                    $( ProverKind::$variant(p) => {
+                        let addr: [MacProver<V, F>; $addr] = addr.try_into().unwrap();
+                        let value = p.remove(dmc, &addr)?;
+                        p.insert(dmc, &addr, &value)?;
+                        Ok(value.to_vec())
+                    } )+
+                }
+            }
+
+            fn write(
+                &mut self,
+                dmc: &mut DietMacAndCheeseProver<V, F, C>,
+                addr: &[MacProver<V, F>],
+                value: &[MacProver<V, F>],
+            ) -> Result<()> {
+                match self {
+                    $( ProverKind::$variant(p) => {
+                        let addr: [MacProver<V, F>; $addr] = addr.try_into().unwrap();
+                        let value: [MacProver<V, F>; $value] = value.try_into().unwrap();
+                        p.remove(dmc, &addr)?;
+                        p.insert(dmc, &addr, &value)?;
+                        Ok(())
+                    } )+
+                }
+            }
+
+            fn finalize(self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+                match self {
+                    $( ProverKind::$variant(p) => p.finalize(dmc) ),+
+                }
+            }
+        }
+    };
+}
+
+prover_kind! {
+    K1x1: (1, 1, 4),
+    K2x1: (2, 1, 5),
+    K1x2: (1, 2, 5),
+    K2x2: (2, 2, 6),
+    K4x4: (4, 4, 10),
+}
+
 pub struct MemoryProver<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
 where
     F::PrimeField: IsSubFieldOf<V>,
 {
-    prover: Option<Prover<V, F, C, Bounded<V>, 1, 1, 3, 2, 4>>,
+    kind: Option<ProverKind<V, F, C>>,
 }
 
 impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> Default for MemoryProver<V, F, C>
@@ -132,7 +301,7 @@ where
     F::PrimeField: IsSubFieldOf<V>,
 {
     fn default() -> Self {
-        Self { prover: None }
+        Self { kind: None }
     }
 }
 
@@ -143,18 +312,14 @@ where
     pub fn read(
         &mut self,
         dmc: &mut DietMacAndCheeseProver<V, F, C>,
-        addr: &MacProver<V, F>,
-    ) -> Result<MacProver<V, F>> {
-        match self.prover.as_mut() {
-            Some(prover) => {
-                let value = prover.remove(dmc, &[*addr])?;
-                prover.insert(dmc, &[*addr], &value)?;
-                Ok(value[0])
-            }
+        addr: &[MacProver<V, F>],
+        value_width: usize,
+    ) -> Result<Vec<MacProver<V, F>>> {
+        match self.kind.as_mut() {
+            Some(kind) => kind.read(dmc, addr),
             None => {
-                let ram = Prover::<V, F, _, _, 1, 1, 3, 2, 4>::new(dmc, Bounded::new(RAM_SIZE));
-                self.prover = Some(ram);
-                self.read(dmc, addr)
+                self.kind = Some(ProverKind::new(dmc, addr.len(), value_width, RAM_SIZE));
+                self.read(dmc, addr, value_width)
             }
         }
     }
@@ -162,36 +327,109 @@ where
     pub fn write(
         &mut self,
         dmc: &mut DietMacAndCheeseProver<V, F, C>,
-        addr: &MacProver<V, F>,
-        value: &MacProver<V, F>,
+        addr: &[MacProver<V, F>],
+        value: &[MacProver<V, F>],
     ) -> Result<()> {
-        match self.prover.as_mut() {
-            Some(prover) => {
-                prover.remove(dmc, &[*addr])?;
-                prover.insert(dmc, &[*addr], &[*value])?;
-                Ok(())
-            }
+        match self.kind.as_mut() {
+            Some(kind) => kind.write(dmc, addr, value),
             None => {
-                let ram = Prover::<V, F, _, _, 1, 1, 3, 2, 4>::new(dmc, Bounded::new(RAM_SIZE));
-                self.prover = Some(ram);
+                self.kind = Some(ProverKind::new(dmc, addr.len(), value.len(), RAM_SIZE));
                 self.write(dmc, addr, value)
             }
         }
     }
 
     pub fn finalize(&mut self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
-        match self.prover.take() {
-            Some(prover) => prover.finalize(dmc),
+        match self.kind.take() {
+            Some(kind) => kind.finalize(dmc),
             None => Ok(()),
         }
     }
 }
 
+/// Verifier-side counterpart of `prover_kind!`: same dispatch, built on
+/// `Verifier`/`MacVerifier` instead of `Prover`/`MacProver`.
+macro_rules! verifier_kind {
+    ($( $variant:ident : ($addr:literal, $value:literal, $dim:literal) ),+ $(,)?) => {
+        enum VerifierKind<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            $( $variant(Verifier<V, F, C, Bounded<V>, $addr, $value, 3, 2, $dim>) ),+
+        }
+
+        impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> VerifierKind<V, F, C>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            fn new(
+                dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+                addr_width: usize,
+                value_width: usize,
+                bound: usize,
+            ) -> Self {
+                match (addr_width, value_width) {
+                    $( ($addr, $value) => VerifierKind::$variant(Verifier::new(dmc, Bounded::new(bound))), )+
+                    (a, v) => panic!(
+                        "unsupported RAM (addr_width={a}, value_width={v}); supported: {SUPPORTED_SIZES:?}"
+                    ),
+                }
+            }
+
+            fn read(
+                &mut self,
+                dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+                addr: &[MacVerifier<F>],
+            ) -> Result<Vec<MacVerifier<F>>> {
+                match self {
+                    $( VerifierKind::$variant(p) => {
+                        let addr: [MacVerifier<F>; $addr] = addr.try_into().unwrap();
+                        let value = p.remove(dmc, &addr)?;
+                        p.insert(dmc, &addr, &value)?;
+                        Ok(value.to_vec())
+                    } )+
+                }
+            }
+
+            fn write(
+                &mut self,
+                dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+                addr: &[MacVerifier<F>],
+                value: &[MacVerifier<F>],
+            ) -> Result<()> {
+                match self {
+                    $( VerifierKind::$variant(p) => {
+                        let addr: [MacVerifier<F>; $addr] = addr.try_into().unwrap();
+                        let value: [MacVerifier<F>; $value] = value.try_into().unwrap();
+                        p.remove(dmc, &addr)?;
+                        p.insert(dmc, &addr, &value)?;
+                        Ok(())
+                    } )+
+                }
+            }
+
+            fn finalize(self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+                match self {
+                    $( VerifierKind::$variant(p) => p.finalize(dmc) ),+
+                }
+            }
+        }
+    };
+}
+
+verifier_kind! {
+    K1x1: (1, 1, 4),
+    K2x1: (2, 1, 5),
+    K1x2: (1, 2, 5),
+    K2x2: (2, 2, 6),
+    K4x4: (4, 4, 10),
+}
+
 pub struct MemoryVerifier<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
 where
     F::PrimeField: IsSubFieldOf<V>,
 {
-    verifier: Option<Verifier<V, F, C, Bounded<V>, 1, 1, 3, 2, 4>>,
+    kind: Option<VerifierKind<V, F, C>>,
 }
 
 impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> Default for MemoryVerifier<V, F, C>
@@ -199,7 +437,7 @@ where
     F::PrimeField: IsSubFieldOf<V>,
 {
     fn default() -> Self {
-        Self { verifier: None }
+        Self { kind: None }
     }
 }
 
@@ -210,18 +448,14 @@ where
     pub fn read(
         &mut self,
         dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
-        addr: &MacVerifier<F>,
-    ) -> Result<MacVerifier<F>> {
-        match self.verifier.as_mut() {
-            Some(verifier) => {
-                let value = verifier.remove(dmc, &[*addr])?;
-                verifier.insert(dmc, &[*addr], &value)?;
-                Ok(value[0])
-            }
+        addr: &[MacVerifier<F>],
+        value_width: usize,
+    ) -> Result<Vec<MacVerifier<F>>> {
+        match self.kind.as_mut() {
+            Some(kind) => kind.read(dmc, addr),
             None => {
-                let ram = Verifier::<V, F, _, _, 1, 1, 3, 2, 4>::new(dmc, Bounded::new(RAM_SIZE));
-                self.verifier = Some(ram);
-                self.read(dmc, addr)
+                self.kind = Some(VerifierKind::new(dmc, addr.len(), value_width, RAM_SIZE));
+                self.read(dmc, addr, value_width)
             }
         }
     }
@@ -229,26 +463,21 @@ where
     pub fn write(
         &mut self,
         dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
-        addr: &MacVerifier<F>,
-        value: &MacVerifier<F>,
+        addr: &[MacVerifier<F>],
+        value: &[MacVerifier<F>],
     ) -> Result<()> {
-        match self.verifier.as_mut() {
-            Some(verifier) => {
-                verifier.remove(dmc, &[*addr])?;
-                verifier.insert(dmc, &[*addr], &[*value])?;
-                Ok(())
-            }
+        match self.kind.as_mut() {
+            Some(kind) => kind.write(dmc, addr, value),
             None => {
-                let ram = Verifier::<V, F, _, _, 1, 1, 3, 2, 4>::new(dmc, Bounded::new(RAM_SIZE));
-                self.verifier = Some(ram);
+                self.kind = Some(VerifierKind::new(dmc, addr.len(), value.len(), RAM_SIZE));
                 self.write(dmc, addr, value)
             }
         }
     }
 
     pub fn finalize(&mut self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
-        match self.verifier.take() {
-            Some(verifier) => verifier.finalize(dmc),
+        match self.kind.take() {
+            Some(kind) => kind.finalize(dmc),
             None => Ok(()),
         }
     }
@@ -1,9 +1,6 @@
 use eyre::Result;
 
-use std::{
-    collections::{hash_map::Entry, HashMap},
-    marker::PhantomData,
-};
+use std::marker::PhantomData;
 
 use rustc_hash::FxHashMap;
 
@@ -13,8 +10,9 @@ use swanky_field::{FiniteField, IsSubFieldOf};
 use std::iter;
 
 use crate::{
+    dora::fiat_shamir,
     homcom::{FComProver, MacProver},
-    ram::{collapse_vecs, perm::permutation, PRE_ALLOC_MEM, PRE_ALLOC_STEPS},
+    ram::{assert_ts_lt, collapse_vecs, perm::permutation, PRE_ALLOC_MEM, PRE_ALLOC_STEPS},
     DietMacAndCheeseProver,
 };
 
@@ -27,6 +25,17 @@ trait MemParams {
     const DIM_VALUE: usize;
 }
 
+/// Offline (Spice/Lasso-style) memory checking: every access (`remove`
+/// followed by `insert`, as `MemoryProver`/`vm` already call it) is tagged
+/// with a monotonic timestamp rather than a fresh random challenge, so the
+/// same address can be written arbitrarily many times — `insert` used to
+/// `unreachable!()` on a second write to the same address, which forced the
+/// read-before-write discipline this module now lifts. Consistency is
+/// proven at [`Self::finalize`] as the multiset equality
+/// `Init ∪ WS == RS ∪ Final` (see there), plus a per-read range check
+/// (`assert_ts_lt`) that the timestamp read back is strictly older than the
+/// access reading it, so a stale `(addr, value, ts)` triple can't be
+/// replayed in place of the real latest write.
 pub struct Prover<
     V: IsSubFieldOf<F>,
     F: FiniteField,
@@ -43,7 +52,13 @@ pub struct Prover<
     space: M,
     _ph: PhantomData<(V, F, C, M)>,
     ch: TxChannel<C>,
-    memory: FxHashMap<[V; SIZE_ADDR], [V; SIZE_STORE]>,
+    // monotonic step counter, in lockstep with `Verifier`'s, kept both as a
+    // native integer (for generating range-check witness bits) and as a
+    // field element (for embedding into committed timestamp tags)
+    ts_native: u64,
+    ts_elem: V,
+    // addr -> (stored value, timestamp tag of the write that produced it)
+    memory: FxHashMap<[V; SIZE_ADDR], ([V; SIZE_VALUE], V, u64)>,
     // reads
     rds: Vec<[MacProver<V, F>; SIZE_DIM]>,
     // writes
@@ -81,44 +96,104 @@ where
             wrs: Vec::with_capacity(PRE_ALLOC_MEM + PRE_ALLOC_STEPS),
             memory: Default::default(),
             ch: TxChannel::new(prover.channel.clone(), Default::default()),
+            ts_native: 1,
+            ts_elem: V::ONE,
             _ph: Default::default(),
         }
     }
 
-    /// Read is a destructive operation which "r"
-    pub fn remove(
+    /// Commit a read-side `(addr, value, ts)` row: `value` and `ts` are both
+    /// only known to the prover (they come out of the local `memory` map),
+    /// so both are freshly witnessed via `input1`, exactly as the old
+    /// destructive `remove` witnessed its "old" value.
+    fn commit_read_row(
         &mut self,
         prover: &mut DietMacAndCheeseProver<V, F, C>,
         addr: &[MacProver<V, F>; SIZE_ADDR],
-    ) -> Result<[MacProver<V, F>; SIZE_VALUE]> {
-        // retrieve old value in memory (destructive)
-        let val_addr = addr.map(|e| e.value());
-        let old = self
-            .memory
-            .remove(&val_addr)
-            .unwrap_or_else(|| [V::default(); SIZE_STORE]);
+        value: &[V; SIZE_VALUE],
+        ts: V,
+    ) -> Result<[MacProver<V, F>; SIZE_DIM]> {
+        let mut tag = [V::default(); SIZE_CHAL];
+        tag[0] = ts;
 
-        // concatenate addr || value || challenge
-        // commit to the old value
         let mut flat: [MacProver<V, F>; SIZE_DIM] = [Default::default(); SIZE_DIM];
-
         for (i, elem) in iter::empty()
             .chain(addr.iter().copied())
-            .chain(old.into_iter().map(|x| {
+            .chain(value.iter().chain(tag.iter()).map(|x| {
                 let m = prover
                     .prover
-                    .input1(&mut self.ch, &mut prover.rng, x)
+                    .input1(&mut self.ch, &mut prover.rng, *x)
                     .unwrap();
-                MacProver::new(x, m)
+                MacProver::new(*x, m)
             }))
             .enumerate()
         {
             flat[i] = elem;
         }
+        Ok(flat)
+    }
+
+    /// Commit a write-side `(addr, value, ts)` row: `ts` is the current
+    /// step counter, which both parties maintain identically, so (like the
+    /// old per-write challenge) it is committed public via `commit_pub`
+    /// rather than witnessed. `value` is taken as-is, already a MacProver
+    /// committed by the caller (or re-witnessed by the caller ahead of
+    /// time, for a plain write-back of an already-read value).
+    fn commit_write_row(
+        &mut self,
+        addr: &[MacProver<V, F>; SIZE_ADDR],
+        value: &[MacProver<V, F>; SIZE_VALUE],
+    ) -> [MacProver<V, F>; SIZE_DIM] {
+        let mut tag = [V::default(); SIZE_CHAL];
+        tag[0] = self.ts_elem;
+
+        let mut flat: [MacProver<V, F>; SIZE_DIM] = [Default::default(); SIZE_DIM];
+        for (i, elem) in iter::empty()
+            .chain(addr.iter().copied())
+            .chain(value.iter().copied())
+            .chain(commit_pub(&tag))
+            .enumerate()
+        {
+            flat[i] = elem;
+        }
+        flat
+    }
 
-        // add to reads
-        self.rds.push(flat);
-        Ok(flat[SIZE_ADDR..SIZE_ADDR + SIZE_VALUE].try_into().unwrap())
+    /// Read `addr`, recording the access as an `RS`/`WS` pair tagged with
+    /// the current timestamp, and leaving `addr`'s stored value unchanged.
+    /// No longer destructive: unlike the old `remove`, this does not evict
+    /// the address from `memory`, so reading the same address twice in a
+    /// row (without an intervening `insert`) is no longer a logic error.
+    pub fn remove(
+        &mut self,
+        prover: &mut DietMacAndCheeseProver<V, F, C>,
+        addr: &[MacProver<V, F>; SIZE_ADDR],
+    ) -> Result<[MacProver<V, F>; SIZE_VALUE]> {
+        let key = addr.map(|e| e.value());
+        let (value, stored_ts_elem, stored_ts_native) = self
+            .memory
+            .get(&key)
+            .copied()
+            .unwrap_or(([V::default(); SIZE_VALUE], V::ZERO, 0));
+
+        let rd = self.commit_read_row(prover, addr, &value, stored_ts_elem)?;
+        assert_ts_lt(
+            prover,
+            &rd[SIZE_ADDR + SIZE_VALUE],
+            Some(stored_ts_native),
+            self.ts_native,
+            self.ts_elem,
+        )?;
+        let value_mac: [MacProver<V, F>; SIZE_VALUE] =
+            rd[SIZE_ADDR..SIZE_ADDR + SIZE_VALUE].try_into().unwrap();
+        self.rds.push(rd);
+
+        let wr = self.commit_write_row(addr, &value_mac);
+        self.wrs.push(wr);
+        self.memory.insert(key, (value, self.ts_elem, self.ts_native));
+        self.bump_ts();
+
+        Ok(value_mac)
     }
 
     pub fn insert(
@@ -130,31 +205,35 @@ where
         debug_assert_eq!(addr.len(), M::DIM_ADDR);
         debug_assert_eq!(value.len(), M::DIM_VALUE);
 
-        // store value || challenge in local map
-        match self.memory.entry(addr.map(|m| m.value())) {
-            Entry::Occupied(_) => {
-                unreachable!("double entry, must remove entry first: this is a logic error")
-            }
-            Entry::Vacant(entry) => {
-                // sample challenge
-                let mut flat: [MacProver<V, F>; SIZE_DIM] = [Default::default(); SIZE_DIM];
-                for (i, elem) in iter::empty()
-                    .chain(addr.iter().copied())
-                    .chain(value.iter().copied())
-                    .chain(commit_pub(&self.ch.challenge::<_, SIZE_CHAL>()))
-                    .enumerate()
-                {
-                    flat[i] = elem;
-                }
-
-                // add to local map
-                let store: &[_; SIZE_STORE] = flat[M::DIM_ADDR..].try_into().unwrap();
-                entry.insert(store.map(|m| m.value()));
-
-                // add to list of writes
-                Ok(self.wrs.push(flat))
-            }
-        }
+        let key = addr.map(|m| m.value());
+        let (stored_value, stored_ts_elem, stored_ts_native) = self
+            .memory
+            .get(&key)
+            .copied()
+            .unwrap_or(([V::default(); SIZE_VALUE], V::ZERO, 0));
+
+        let rd = self.commit_read_row(prover, addr, &stored_value, stored_ts_elem)?;
+        assert_ts_lt(
+            prover,
+            &rd[SIZE_ADDR + SIZE_VALUE],
+            Some(stored_ts_native),
+            self.ts_native,
+            self.ts_elem,
+        )?;
+        self.rds.push(rd);
+
+        let wr = self.commit_write_row(addr, value);
+        self.wrs.push(wr);
+        let new_value = value.map(|m| m.value());
+        self.memory.insert(key, (new_value, self.ts_elem, self.ts_native));
+        self.bump_ts();
+
+        Ok(())
+    }
+
+    fn bump_ts(&mut self) {
+        self.ts_native += 1;
+        self.ts_elem += V::ONE;
     }
 
     pub fn finalize(mut self, prover: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
@@ -164,23 +243,49 @@ where
             self.space.size()
         );
 
-        // insert initial values into the bag
-        let mut pre: [MacProver<V, F>; SIZE_DIM] = commit_pub(&[V::default(); SIZE_DIM]);
-
-        // remove every address from the bag
+        // Init: every address in the space at its untouched default, ts=0.
+        // That row is fully public, so it commits with a zero MAC via
+        // `commit_pub` exactly like the old per-address removal loop did.
+        // Final: every address's last-known (value, ts), or the same
+        // default if it was never accessed. Unlike Init, the verifier must
+        // never learn these in the clear, so -- exactly like any other
+        // read -- `value`/`ts` are freshly witnessed via `commit_read_row`
+        // rather than committed public.
         for addr in self.space.enumerate() {
-            let addr = commit_pub(&addr.as_ref().try_into().unwrap());
-            pre[..M::DIM_ADDR].copy_from_slice(&addr);
-            self.wrs.push(pre.clone());
-            self.remove(prover, &addr)?;
+            let addr: [V; SIZE_ADDR] = addr.as_ref().try_into().unwrap();
+            let key = addr;
+            let addr_mac = commit_pub(&addr);
+
+            let mut init_row: [MacProver<V, F>; SIZE_DIM] = commit_pub(&[V::default(); SIZE_DIM]);
+            init_row[..SIZE_ADDR].copy_from_slice(&addr_mac);
+            self.wrs.push(init_row);
+
+            let (value, ts_elem, _) = self
+                .memory
+                .get(&key)
+                .copied()
+                .unwrap_or(([V::default(); SIZE_VALUE], V::ZERO, 0));
+            let rd = self.commit_read_row(prover, &addr_mac, &value, ts_elem)?;
+            self.rds.push(rd);
         }
 
-        // run permutation check
+        // run permutation check: Init ++ WS ~ RS ++ Final
         assert_eq!(self.rds.len(), self.wrs.len());
 
-        prover.channel.flush()?;
-        let chal_cmbn = prover.channel.read_serializable::<V>()?;
-        let chal_perm1 = prover.channel.read_serializable::<V>()?;
+        // Every witnessed read-row MAC (including the Init/Final loop just
+        // above) passed through `self.ch`, so by this point its transcript
+        // already binds the challenges to everything the prover has
+        // committed: in the Fiat-Shamir case, squeeze them straight out of
+        // it instead of round-tripping over the channel.
+        let (chal_cmbn, chal_perm1) = if fiat_shamir::<V>() {
+            let [chal_cmbn, chal_perm1] = self.ch.challenge::<V, 2>();
+            (chal_cmbn, chal_perm1)
+        } else {
+            prover.channel.flush()?;
+            let chal_cmbn = prover.channel.read_serializable::<V>()?;
+            let chal_perm1 = prover.channel.read_serializable::<V>()?;
+            (chal_cmbn, chal_perm1)
+        };
 
         log::debug!("collapse wrs");
         let wrs = collapse_vecs(prover, &self.wrs, chal_cmbn)?;
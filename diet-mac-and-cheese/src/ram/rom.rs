@@ -0,0 +1,384 @@
+//! Preinitialized read-only memory: a thin wrapper around
+//! [`super::Prover`]/[`super::Verifier`] that seeds every address with its
+//! initial value once at construction (via `insert`), then only ever
+//! serves non-destructive reads. No dedicated subset argument is needed:
+//! [`super::Prover`]'s timestamp-based `Init ∪ WS == RS ∪ Final`
+//! permutation check (see `ram/prover.rs`) already guarantees every read
+//! returns exactly the value it was initialized with, and a repeated read
+//! of the same address is just another non-destructive `remove`, so a ROM
+//! falls directly out of that machinery by never calling `insert` again
+//! after setup.
+use eyre::Result;
+
+use scuttlebutt::AbstractChannel;
+use swanky_field::{FiniteField, IsSubFieldOf};
+
+use crate::{
+    backend_trait::BackendT,
+    homcom::{MacProver, MacVerifier},
+    DietMacAndCheeseProver, DietMacAndCheeseVerifier,
+};
+
+use super::{Bounded, MemorySpace, Prover, Verifier, RAM_SIZE};
+
+/// Prover side of a preinitialized ROM. See the module docs.
+pub struct RomProver<
+    V: IsSubFieldOf<F>,
+    F: FiniteField,
+    C: AbstractChannel,
+    M: MemorySpace<V>,
+    const SIZE_ADDR: usize,
+    const SIZE_VALUE: usize,
+    const SIZE_STORE: usize,
+    const SIZE_CHAL: usize,
+    const SIZE_DIM: usize,
+> where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    inner: Prover<V, F, C, M, SIZE_ADDR, SIZE_VALUE, SIZE_STORE, SIZE_CHAL, SIZE_DIM>,
+}
+
+impl<
+        V: IsSubFieldOf<F>,
+        F: FiniteField,
+        C: AbstractChannel,
+        M: MemorySpace<V>,
+        const SIZE_ADDR: usize,
+        const SIZE_VALUE: usize,
+        const SIZE_STORE: usize,
+        const SIZE_CHAL: usize,
+        const SIZE_DIM: usize,
+    > RomProver<V, F, C, M, SIZE_ADDR, SIZE_VALUE, SIZE_STORE, SIZE_CHAL, SIZE_DIM>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    /// Commit the initial `addr -> value` image: addresses are public (the
+    /// program/table layout both parties agree on ahead of time), values
+    /// are witnessed (a constant table may still be secret to the
+    /// verifier).
+    pub fn new(
+        dmc: &mut DietMacAndCheeseProver<V, F, C>,
+        space: M,
+        image: impl IntoIterator<Item = ([V; SIZE_ADDR], [V; SIZE_VALUE])>,
+    ) -> Result<Self> {
+        let mut inner = Prover::new(dmc, space);
+        for (addr, value) in image {
+            let addr_mac: [MacProver<V, F>; SIZE_ADDR] = addr
+                .iter()
+                .map(|a| dmc.input_public(*a))
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
+                .unwrap();
+            let value_mac: [MacProver<V, F>; SIZE_VALUE] = value
+                .iter()
+                .map(|v| dmc.input_private(Some(*v)))
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
+                .unwrap();
+            inner.insert(dmc, &addr_mac, &value_mac)?;
+        }
+        Ok(Self { inner })
+    }
+
+    /// Read `addr`. Writes are intentionally not exposed: this is the only
+    /// access this type offers.
+    pub fn read(
+        &mut self,
+        dmc: &mut DietMacAndCheeseProver<V, F, C>,
+        addr: &[MacProver<V, F>; SIZE_ADDR],
+    ) -> Result<[MacProver<V, F>; SIZE_VALUE]> {
+        self.inner.remove(dmc, addr)
+    }
+
+    pub fn finalize(self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+        self.inner.finalize(dmc)
+    }
+}
+
+/// Verifier side of a preinitialized ROM. See the module docs.
+pub struct RomVerifier<
+    V: IsSubFieldOf<F>,
+    F: FiniteField,
+    C: AbstractChannel,
+    M: MemorySpace<V>,
+    const SIZE_ADDR: usize,
+    const SIZE_VALUE: usize,
+    const SIZE_STORE: usize,
+    const SIZE_CHAL: usize,
+    const SIZE_DIM: usize,
+> where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    inner: Verifier<V, F, C, M, SIZE_ADDR, SIZE_VALUE, SIZE_STORE, SIZE_CHAL, SIZE_DIM>,
+}
+
+impl<
+        V: IsSubFieldOf<F>,
+        F: FiniteField,
+        C: AbstractChannel,
+        M: MemorySpace<V>,
+        const SIZE_ADDR: usize,
+        const SIZE_VALUE: usize,
+        const SIZE_STORE: usize,
+        const SIZE_CHAL: usize,
+        const SIZE_DIM: usize,
+    > RomVerifier<V, F, C, M, SIZE_ADDR, SIZE_VALUE, SIZE_STORE, SIZE_CHAL, SIZE_DIM>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    /// Receive the image's (public) address layout; the values themselves
+    /// are witnessed as opaque MACs, exactly like [`RomProver::new`] does
+    /// for the prover, just without knowing the plaintext.
+    pub fn new(
+        verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
+        space: M,
+        addrs: impl IntoIterator<Item = [V; SIZE_ADDR]>,
+    ) -> Result<Self> {
+        let mut inner = Verifier::new(verifier, space);
+        for addr in addrs {
+            let addr_mac: [MacVerifier<F>; SIZE_ADDR] = addr
+                .iter()
+                .map(|a| verifier.input_public(*a))
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
+                .unwrap();
+            let value_mac: [MacVerifier<F>; SIZE_VALUE] = (0..SIZE_VALUE)
+                .map(|_| verifier.input_private(None))
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
+                .unwrap();
+            inner.insert(verifier, &addr_mac, &value_mac)?;
+        }
+        Ok(Self { inner })
+    }
+
+    pub fn read(
+        &mut self,
+        verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
+        addr: &[MacVerifier<F>],
+    ) -> Result<[MacVerifier<F>; SIZE_VALUE]> {
+        self.inner.remove(verifier, addr)
+    }
+
+    pub fn finalize(self, verifier: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+        self.inner.finalize(verifier)
+    }
+}
+
+/// `galois_ram_v0`'s `"read_only"` operation (see `plugins/ram.rs`) carries
+/// no image of its own -- there's no write arm for it in the IR, so nothing
+/// ever supplies initial `addr -> value` pairs. The ROM it backs therefore
+/// starts out entirely at its field's default value (exactly like an
+/// untouched [`super::MemoryProver`] address) and, since it's never
+/// writable afterwards, simply stays that way. `RomMemoryProver` mirrors
+/// `MemoryProver`'s lazy, width-dispatching construction (see
+/// `ram::mod::prover_kind!`) so `rom_read` call sites don't need to know
+/// `SIZE_ADDR`/`SIZE_VALUE` ahead of time.
+macro_rules! rom_prover_kind {
+    ($( $variant:ident : ($addr:literal, $value:literal, $dim:literal) ),+ $(,)?) => {
+        enum RomProverKind<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            $( $variant(RomProver<V, F, C, Bounded<V>, $addr, $value, 3, 2, $dim>) ),+
+        }
+
+        impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> RomProverKind<V, F, C>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            fn new(
+                dmc: &mut DietMacAndCheeseProver<V, F, C>,
+                addr_width: usize,
+                value_width: usize,
+                bound: usize,
+            ) -> Result<Self> {
+                Ok(match (addr_width, value_width) {
+                    $( ($addr, $value) => {
+                        RomProverKind::$variant(RomProver::new(dmc, Bounded::new(bound), std::iter::empty())?)
+                    } )+
+                    (a, v) => panic!(
+                        "unsupported ROM (addr_width={a}, value_width={v}); supported: {:?}",
+                        super::SUPPORTED_SIZES,
+                    ),
+                })
+            }
+
+            fn read(
+                &mut self,
+                dmc: &mut DietMacAndCheeseProver<V, F, C>,
+                addr: &[MacProver<V, F>],
+            ) -> Result<Vec<MacProver<V, F>>> {
+                match self {
+                    $( RomProverKind::$variant(p) => {
+                        let addr: [MacProver<V, F>; $addr] = addr.try_into().unwrap();
+                        Ok(p.read(dmc, &addr)?.to_vec())
+                    } )+
+                }
+            }
+
+            fn finalize(self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+                match self {
+                    $( RomProverKind::$variant(p) => p.finalize(dmc) ),+
+                }
+            }
+        }
+    };
+}
+
+rom_prover_kind! {
+    K1x1: (1, 1, 4),
+    K2x1: (2, 1, 5),
+    K1x2: (1, 2, 5),
+    K2x2: (2, 2, 6),
+    K4x4: (4, 4, 10),
+}
+
+/// Prover-side lazy width dispatcher for `rom_read`. See
+/// [`rom_prover_kind!`].
+pub struct RomMemoryProver<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    kind: Option<RomProverKind<V, F, C>>,
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> Default for RomMemoryProver<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    fn default() -> Self {
+        Self { kind: None }
+    }
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> RomMemoryProver<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pub fn read(
+        &mut self,
+        dmc: &mut DietMacAndCheeseProver<V, F, C>,
+        addr: &[MacProver<V, F>],
+        value_width: usize,
+    ) -> Result<Vec<MacProver<V, F>>> {
+        match self.kind.as_mut() {
+            Some(kind) => kind.read(dmc, addr),
+            None => {
+                self.kind = Some(RomProverKind::new(dmc, addr.len(), value_width, super::RAM_SIZE)?);
+                self.read(dmc, addr, value_width)
+            }
+        }
+    }
+
+    pub fn finalize(&mut self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+        match self.kind.take() {
+            Some(kind) => kind.finalize(dmc),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Verifier-side counterpart of [`rom_prover_kind!`]: same dispatch, built
+/// on [`RomVerifier`]/[`MacVerifier`] instead of [`RomProver`]/[`MacProver`].
+macro_rules! rom_verifier_kind {
+    ($( $variant:ident : ($addr:literal, $value:literal, $dim:literal) ),+ $(,)?) => {
+        enum RomVerifierKind<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            $( $variant(RomVerifier<V, F, C, Bounded<V>, $addr, $value, 3, 2, $dim>) ),+
+        }
+
+        impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> RomVerifierKind<V, F, C>
+        where
+            F::PrimeField: IsSubFieldOf<V>,
+        {
+            fn new(
+                dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+                addr_width: usize,
+                value_width: usize,
+                bound: usize,
+            ) -> Result<Self> {
+                Ok(match (addr_width, value_width) {
+                    $( ($addr, $value) => {
+                        RomVerifierKind::$variant(RomVerifier::new(dmc, Bounded::new(bound), std::iter::empty())?)
+                    } )+
+                    (a, v) => panic!(
+                        "unsupported ROM (addr_width={a}, value_width={v}); supported: {:?}",
+                        super::SUPPORTED_SIZES,
+                    ),
+                })
+            }
+
+            fn read(
+                &mut self,
+                dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+                addr: &[MacVerifier<F>],
+            ) -> Result<Vec<MacVerifier<F>>> {
+                match self {
+                    $( RomVerifierKind::$variant(p) => Ok(p.read(dmc, addr)?.to_vec()) )+
+                }
+            }
+
+            fn finalize(self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+                match self {
+                    $( RomVerifierKind::$variant(p) => p.finalize(dmc) ),+
+                }
+            }
+        }
+    };
+}
+
+rom_verifier_kind! {
+    K1x1: (1, 1, 4),
+    K2x1: (2, 1, 5),
+    K1x2: (1, 2, 5),
+    K2x2: (2, 2, 6),
+    K4x4: (4, 4, 10),
+}
+
+/// Verifier-side lazy width dispatcher for `rom_read`. See
+/// [`rom_verifier_kind!`].
+pub struct RomMemoryVerifier<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    kind: Option<RomVerifierKind<V, F, C>>,
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> Default for RomMemoryVerifier<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    fn default() -> Self {
+        Self { kind: None }
+    }
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> RomMemoryVerifier<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pub fn read(
+        &mut self,
+        dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+        addr: &[MacVerifier<F>],
+        value_width: usize,
+    ) -> Result<Vec<MacVerifier<F>>> {
+        match self.kind.as_mut() {
+            Some(kind) => kind.read(dmc, addr),
+            None => {
+                self.kind = Some(RomVerifierKind::new(dmc, addr.len(), value_width, super::RAM_SIZE)?);
+                self.read(dmc, addr, value_width)
+            }
+        }
+    }
+
+    pub fn finalize(&mut self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+        match self.kind.take() {
+            Some(kind) => kind.finalize(dmc),
+            None => Ok(()),
+        }
+    }
+}
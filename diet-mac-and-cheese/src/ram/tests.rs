@@ -228,3 +228,75 @@ fn test_ram() {
     // wait for prover
     handle.join().unwrap();
 }
+
+/// A timestamp gap of `2^TS_BITS` can't be represented by `assert_ts_lt`'s
+/// committed bit-decomposition: both parties end up committing only the
+/// gap's low `TS_BITS` bits, which no longer satisfy the field equation
+/// against the real (public) `current_ts - stored_ts - 1`. This confirms
+/// the range check added for `TS_BITS` (see its doc comment) actually
+/// rejects an oversized gap instead of silently wrapping it through the
+/// field, as the old 64-bit-wide version did over `F61p`.
+#[test]
+fn test_ts_bits_rejects_timestamp_gap_too_large_to_commit() {
+    let stored_ts_native: u64 = 0;
+    let current_ts_native: u64 = (1u64 << super::TS_BITS) + 1;
+    let current_ts_elem = F61p::try_from(current_ts_native as u128).unwrap();
+
+    let (sender, receiver) = UnixStream::pair().unwrap();
+
+    let handle = std::thread::spawn(move || {
+        let rng = AesRng::from_seed(Default::default());
+        let reader = BufReader::new(sender.try_clone().unwrap());
+        let writer = BufWriter::new(sender);
+        let mut channel = Channel::new(reader, writer);
+
+        let mut prover: DietMacAndCheeseProver<F61p, F61p, _> = DietMacAndCheeseProver::init(
+            &mut channel,
+            rng,
+            LPN_SETUP_MEDIUM,
+            LPN_EXTEND_MEDIUM,
+            false,
+        )
+        .unwrap();
+
+        let stored_ts_wire = prover.constant(F61p::ZERO).unwrap();
+        super::assert_ts_lt(
+            &mut prover,
+            &stored_ts_wire,
+            Some(stored_ts_native),
+            current_ts_native,
+            current_ts_elem,
+        )
+        .unwrap();
+
+        let _ = prover.finalize().unwrap_err();
+    });
+
+    let rng = AesRng::from_seed(Default::default());
+    let reader = BufReader::new(receiver.try_clone().unwrap());
+    let writer = BufWriter::new(receiver);
+    let mut channel = Channel::new(reader, writer);
+
+    let mut verifier: DietMacAndCheeseVerifier<F61p, F61p, _> = DietMacAndCheeseVerifier::init(
+        &mut channel,
+        rng,
+        LPN_SETUP_MEDIUM,
+        LPN_EXTEND_MEDIUM,
+        false,
+    )
+    .unwrap();
+
+    let stored_ts_wire = verifier.constant(F61p::ZERO).unwrap();
+    super::assert_ts_lt(
+        &mut verifier,
+        &stored_ts_wire,
+        None,
+        current_ts_native,
+        current_ts_elem,
+    )
+    .unwrap();
+
+    let _ = verifier.finalize().unwrap_err();
+
+    handle.join().unwrap();
+}
@@ -0,0 +1,89 @@
+//! Transport abstraction for the RAM prover/verifier CLI.
+//!
+//! `ram::Prover`/`ram::Verifier` only ever see an [`AbstractChannel`](scuttlebutt::AbstractChannel),
+//! so the driving loop is free to hand them a connection obtained from any
+//! duplex byte stream. [`RamTransport`] captures the blocking-socket case
+//! (today's `TcpStream`-based CLI); [`AsyncRamTransport`], behind the
+//! `tokio` feature, captures the async case so the same driving loop shape
+//! can serve many concurrent sessions on a tokio runtime instead of one
+//! blocking connection per process.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// A duplex, blocking byte stream that can be split into independent
+/// reader/writer halves, e.g. by `try_clone`-ing the underlying socket.
+pub trait RamTransport: Sized {
+    type Reader: Read;
+    type Writer: Write;
+
+    /// Split the transport into its reader/writer halves.
+    fn split(self) -> std::io::Result<(Self::Reader, Self::Writer)>;
+}
+
+impl RamTransport for TcpStream {
+    type Reader = TcpStream;
+    type Writer = TcpStream;
+
+    fn split(self) -> std::io::Result<(Self::Reader, Self::Writer)> {
+        let writer = self.try_clone()?;
+        Ok((self, writer))
+    }
+}
+
+/// The async counterpart of [`RamTransport`], for running the proving
+/// session on a tokio runtime so that many sessions can be multiplexed onto
+/// a single process rather than one blocking connection per process.
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+pub trait AsyncRamTransport: Sized {
+    type Reader: tokio::io::AsyncRead + Unpin + Send;
+    type Writer: tokio::io::AsyncWrite + Unpin + Send;
+
+    /// Split the transport into its reader/writer halves.
+    fn split(self) -> (Self::Reader, Self::Writer);
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+impl AsyncRamTransport for tokio::net::TcpStream {
+    type Reader = tokio::net::tcp::OwnedReadHalf;
+    type Writer = tokio::net::tcp::OwnedWriteHalf;
+
+    fn split(self) -> (Self::Reader, Self::Writer) {
+        tokio::net::TcpStream::into_split(self)
+    }
+}
+
+/// Adapts an [`AsyncRamTransport`] into a [`RamTransport`] by bridging its
+/// async halves to blocking `Read`/`Write` with [`SyncIoBridge`], so a
+/// connection accepted on a tokio listener can be driven by the same
+/// synchronous proving loop a plain `TcpStream` is, from inside
+/// `spawn_blocking`, without first demoting it to a `std::net::TcpStream`
+/// (which would give up tokio's I/O driver for the rest of that session).
+#[cfg(feature = "tokio")]
+pub struct AsyncTransportBridge<T: AsyncRamTransport> {
+    reader: T::Reader,
+    writer: T::Writer,
+}
+
+#[cfg(feature = "tokio")]
+impl<T: AsyncRamTransport> AsyncTransportBridge<T> {
+    pub fn new(transport: T) -> Self {
+        let (reader, writer) = transport.split();
+        Self { reader, writer }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T: AsyncRamTransport> RamTransport for AsyncTransportBridge<T> {
+    type Reader = tokio_util::io::SyncIoBridge<T::Reader>;
+    type Writer = tokio_util::io::SyncIoBridge<T::Writer>;
+
+    fn split(self) -> std::io::Result<(Self::Reader, Self::Writer)> {
+        Ok((
+            tokio_util::io::SyncIoBridge::new(self.reader),
+            tokio_util::io::SyncIoBridge::new(self.writer),
+        ))
+    }
+}
@@ -1,3 +1,4 @@
+//! Fiat-Shamir transcript channel.
 use std::io::Result;
 
 use scuttlebutt::{field::FiniteField, AbstractChannel};
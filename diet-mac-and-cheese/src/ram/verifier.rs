@@ -1,9 +1,6 @@
 use eyre::Result;
 
-use std::{
-    collections::{hash_map::Entry, HashMap},
-    marker::PhantomData,
-};
+use std::marker::PhantomData;
 
 use scuttlebutt::{AbstractChannel, AesRng};
 use swanky_field::{FiniteField, IsSubFieldOf};
@@ -12,8 +9,9 @@ use std::iter;
 
 use crate::{
     backend_trait::BackendT,
+    dora::fiat_shamir,
     homcom::{FComProver, MacProver, MacVerifier},
-    ram::{collapse_vecs, perm::permutation},
+    ram::{assert_ts_lt, collapse_vecs, perm::permutation},
     DietMacAndCheeseVerifier,
 };
 
@@ -47,6 +45,13 @@ where
         .map(|(t, v)| MacProver::new(v, t)))
 }
 
+/// Mirrors [`super::prover::Prover`]: every access is an `RS`/`WS` row
+/// tagged with a monotonic timestamp instead of a random per-write
+/// challenge, checked via the `Init ∪ WS == RS ∪ Final` permutation in
+/// [`Self::finalize`] plus a per-read `assert_ts_lt` call. This side has no
+/// local copy of the RAM contents: `remove`/`insert` only ever witness
+/// opaque MACs (via `.input(..)`) and rely on that permutation check, not a
+/// `memory` map, to catch an inconsistent value or timestamp.
 pub struct Verifier<
     V: IsSubFieldOf<F>,
     F: FiniteField,
@@ -64,6 +69,9 @@ pub struct Verifier<
     ch: TxChannel<C>,
     _ph: PhantomData<(V, F, C, M)>,
     tx: blake3::Hasher,
+    // monotonic step counter, kept in lockstep with `Prover`'s
+    ts_native: u64,
+    ts_elem: V,
     // reads
     rds: Vec<[MacVerifier<F>; SIZE_DIM]>,
     // writes
@@ -91,22 +99,22 @@ where
             rds: Vec::with_capacity(PRE_ALLOC_MEM + PRE_ALLOC_STEPS),
             wrs: Vec::with_capacity(PRE_ALLOC_MEM + PRE_ALLOC_STEPS),
             tx: Default::default(),
+            ts_native: 1,
+            ts_elem: V::ONE,
             _ph: Default::default(),
         }
     }
 
-    /// Read is a destructive operation which "r"
-    pub fn remove(
+    /// Witness a read-side `(addr, value, ts)` row as opaque MACs, then
+    /// force the zero-padding slots of the tag (everything past `ts`
+    /// itself) to the public constant `0`, matching the prover's
+    /// already-zero padding there.
+    fn commit_read_row(
         &mut self,
         verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
         addr: &[MacVerifier<F>],
-    ) -> Result<[MacVerifier<F>; SIZE_VALUE]> {
-        debug_assert_eq!(addr.len(), M::DIM_ADDR);
-
-        // concatenate addr || value || challenge
-        // commit to the old value
+    ) -> Result<[MacVerifier<F>; SIZE_DIM]> {
         let mut flat = [Default::default(); SIZE_DIM];
-
         for (i, elem) in iter::empty()
             .chain(addr.iter().copied())
             .chain(
@@ -119,63 +127,153 @@ where
         {
             flat[i] = elem;
         }
-
-        // add to reads
-        self.rds.push(flat);
-        Ok(flat[SIZE_ADDR..SIZE_ADDR + SIZE_VALUE].try_into().unwrap())
+        for pad in &flat[SIZE_ADDR + SIZE_VALUE + 1..] {
+            verifier.assert_zero(pad)?;
+        }
+        Ok(flat)
     }
 
-    pub fn insert(
+    /// Witness a write-side `(addr, value, ts)` row: `ts` is the public
+    /// step counter, committed via `input_public` like the old per-write
+    /// challenge was.
+    fn commit_write_row(
         &mut self,
         verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
         addr: &[MacVerifier<F>; SIZE_ADDR],
         value: &[MacVerifier<F>; SIZE_VALUE],
-    ) -> Result<()> {
-        debug_assert_eq!(addr.len(), M::DIM_ADDR);
-        debug_assert_eq!(value.len(), M::DIM_VALUE);
+    ) -> Result<[MacVerifier<F>; SIZE_DIM]> {
+        let mut tag = [V::default(); SIZE_CHAL];
+        tag[0] = self.ts_elem;
 
-        // sample challenge
         let mut flat = [Default::default(); SIZE_DIM];
         for (i, elem) in iter::empty()
-            .chain(*addr)
-            .chain(*value)
-            .chain(
-                self.ch
-                    .challenge::<_, SIZE_CHAL>()
-                    .map(|x| verifier.input_public(x).unwrap()),
-            )
+            .chain(addr.iter().copied())
+            .chain(value.iter().copied())
+            .chain(tag.iter().map(|x| verifier.input_public(*x).unwrap()))
             .enumerate()
         {
             flat[i] = elem;
         }
+        Ok(flat)
+    }
+
+    fn bump_ts(&mut self) {
+        self.ts_native += 1;
+        self.ts_elem += V::ONE;
+    }
+
+    /// See [`super::prover::Prover::remove`]: no longer destructive, so the
+    /// same address may be read any number of times in a row.
+    pub fn remove(
+        &mut self,
+        verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
+        addr: &[MacVerifier<F>],
+    ) -> Result<[MacVerifier<F>; SIZE_VALUE]> {
+        debug_assert_eq!(addr.len(), M::DIM_ADDR);
+
+        let rd = self.commit_read_row(verifier, addr)?;
+        assert_ts_lt(
+            verifier,
+            &rd[SIZE_ADDR + SIZE_VALUE],
+            None,
+            self.ts_native,
+            self.ts_elem,
+        )?;
+        let value: [MacVerifier<F>; SIZE_VALUE] =
+            rd[SIZE_ADDR..SIZE_ADDR + SIZE_VALUE].try_into().unwrap();
+        self.rds.push(rd);
+
+        let addr_arr: [MacVerifier<F>; SIZE_ADDR] = addr.try_into().unwrap();
+        let wr = self.commit_write_row(verifier, &addr_arr, &value)?;
+        self.wrs.push(wr);
+        self.bump_ts();
+
+        Ok(value)
+    }
+
+    pub fn insert(
+        &mut self,
+        verifier: &mut DietMacAndCheeseVerifier<V, F, C>,
+        addr: &[MacVerifier<F>; SIZE_ADDR],
+        value: &[MacVerifier<F>; SIZE_VALUE],
+    ) -> Result<()> {
+        debug_assert_eq!(addr.len(), M::DIM_ADDR);
+        debug_assert_eq!(value.len(), M::DIM_VALUE);
+
+        let rd = self.commit_read_row(verifier, addr)?;
+        assert_ts_lt(
+            verifier,
+            &rd[SIZE_ADDR + SIZE_VALUE],
+            None,
+            self.ts_native,
+            self.ts_elem,
+        )?;
+        self.rds.push(rd);
+
+        let wr = self.commit_write_row(verifier, addr, value)?;
+        self.wrs.push(wr);
+        self.bump_ts();
 
-        // add to list of writes
-        self.wrs.push(flat);
         Ok(())
     }
 
     pub fn finalize(mut self, verifier: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
-        // insert initial values into the bag
-        let mut pre = [V::default(); SIZE_DIM].map(|x| verifier.input_public(x).unwrap());
-
-        // remove every address from the bag
+        // Init: every address in the space at its untouched default, ts=0.
+        // Final: the RAM's actual final contents, fully opaque (the
+        // verifier never learns plaintext values) but tagged with the
+        // timestamp of whichever write last touched the address, or the
+        // same default if it was untouched — mirroring `Prover::finalize`
+        // exactly, just without ever materializing a `memory` map.
         for addr in self.space.enumerate() {
             let addr: Vec<_> = addr
                 .as_ref()
                 .iter()
                 .map(|x| verifier.input_public(*x).unwrap())
                 .collect();
+            let addr: [MacVerifier<F>; SIZE_ADDR] = addr.try_into().unwrap();
+
+            let mut init_row =
+                [V::default(); SIZE_DIM].map(|x| verifier.input_public(x).unwrap());
+            init_row[..SIZE_ADDR].copy_from_slice(&addr);
+            self.wrs.push(init_row);
+
+            let final_value: [MacVerifier<F>; SIZE_VALUE] = verifier
+                .verifier
+                .input(&mut self.ch, &mut verifier.rng, SIZE_VALUE)
+                .unwrap()
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            let final_tag: [MacVerifier<F>; SIZE_CHAL] = verifier
+                .verifier
+                .input(&mut self.ch, &mut verifier.rng, SIZE_CHAL)
+                .unwrap()
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
 
-            pre[..M::DIM_ADDR].copy_from_slice(&addr);
-            self.wrs.push(pre.clone());
-            self.remove(verifier, &addr)?;
+            let mut final_row = [Default::default(); SIZE_DIM];
+            final_row[..SIZE_ADDR].copy_from_slice(&addr);
+            final_row[SIZE_ADDR..SIZE_ADDR + SIZE_VALUE].copy_from_slice(&final_value);
+            final_row[SIZE_ADDR + SIZE_VALUE..].copy_from_slice(&final_tag);
+            self.rds.push(final_row);
         }
 
-        let chal_cmbn = V::random(&mut verifier.rng);
-        let chal_perm1 = V::random(&mut verifier.rng);
-        verifier.channel.write_serializable(&chal_cmbn)?;
-        verifier.channel.write_serializable(&chal_perm1)?;
-        verifier.channel.flush()?;
+        // Mirrors `Prover::finalize`: the transcript already carries every
+        // read-row MAC witnessed through `self.ch`, so a Fiat-Shamir
+        // verifier squeezes the same challenges out of it instead of
+        // sampling and sending them.
+        let (chal_cmbn, chal_perm1) = if fiat_shamir::<V>() {
+            let [chal_cmbn, chal_perm1] = self.ch.challenge::<V, 2>();
+            (chal_cmbn, chal_perm1)
+        } else {
+            let chal_cmbn = V::random(&mut verifier.rng);
+            let chal_perm1 = V::random(&mut verifier.rng);
+            verifier.channel.write_serializable(&chal_cmbn)?;
+            verifier.channel.write_serializable(&chal_perm1)?;
+            verifier.channel.flush()?;
+            (chal_cmbn, chal_perm1)
+        };
 
         let wrs = collapse_vecs(verifier, &self.wrs, chal_cmbn)?;
         let rds = collapse_vecs(verifier, &self.rds, chal_cmbn)?;
@@ -0,0 +1,473 @@
+//! A generic register-machine execution proof built on top of
+//! [`MemoryProver`]/[`MemoryVerifier`].
+//!
+//! `MemoryProver`/`MemoryVerifier` only expose a raw `read`/`write` on a
+//! single untyped memory. This module turns that primitive into a small,
+//! fixed-width load/store/ALU/branch ISA: state is three `Bounded` memories
+//! (program, data, and a small register file) plus a program-counter wire,
+//! and [`VmProver::step`]/[`VmVerifier::step`] prove one
+//! fetch-decode-execute transition at a time. The existing RAM
+//! permutation/consistency machinery (`MemoryProver::finalize`/
+//! `MemoryVerifier::finalize`) already proves that every access to each of
+//! the three memories is consistent across steps; this module only has to
+//! enforce the per-step transition relation.
+//!
+//! Dispatch over the opcode is a disjunction where exactly one branch is
+//! "active" per step: every opcode's gadget runs on every step, but its
+//! memory accesses and register writeback are masked by an `is_op`
+//! selector, computed with the standard is-zero gadget below, so an
+//! inactive branch degenerates into a no-op identity access and the RAM
+//! permutation checks stay balanced regardless of which opcode actually
+//! executed.
+//!
+//! Each instruction occupies four consecutive cells of program memory
+//! (`op`, `rd`, `rs`, `imm`), rather than one cell packed as
+//! `op + rd*B + rs*B^2 + imm*B^3`. [`encode_instruction`] still exposes that
+//! packed encoding (built from [`combine`]) for callers that want a single
+//! canonical id per instruction (e.g. for hashing a program into a
+//! commitment); unpacking it back out in-circuit needs a digit
+//! range-checked decomposition gadget, which is out of scope here and is
+//! intended to ride on the chunked range-check machinery instead.
+
+use eyre::Result;
+use scuttlebutt::AbstractChannel;
+use swanky_field::{FiniteField, IsSubFieldOf};
+
+use crate::{
+    backend_trait::BackendT,
+    homcom::{MacProver, MacVerifier},
+    DietMacAndCheeseProver, DietMacAndCheeseVerifier,
+};
+
+use super::{combine, MemoryProver, MemoryVerifier};
+
+/// The public radix `B` used by [`encode_instruction`]'s packed encoding:
+/// `word = op + rd*B + rs*B^2 + imm*B^3`.
+pub const RADIX: u64 = 1 << 16;
+
+/// Opcodes of the fixed-width ISA. Each opcode is a small public constant
+/// embedded directly into the instruction's `op` cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    /// `rd <- mem[rs]`
+    Load,
+    /// `mem[rd] <- rs`
+    Store,
+    /// `rd <- rs + imm`
+    Add,
+    /// `rd <- rs - imm`
+    Sub,
+    /// `pc <- imm`
+    Jmp,
+    /// `pc <- imm if rd == rs, else pc + 1`
+    Beq,
+}
+
+/// All opcodes, in the order their selector branches are evaluated.
+pub const OPCODES: [Opcode; 6] = [
+    Opcode::Load,
+    Opcode::Store,
+    Opcode::Add,
+    Opcode::Sub,
+    Opcode::Jmp,
+    Opcode::Beq,
+];
+
+impl Opcode {
+    /// The public field constant this opcode is encoded as.
+    fn code<F: FiniteField>(self) -> F {
+        let n = OPCODES.iter().position(|o| *o == self).unwrap();
+        let mut x = F::ZERO;
+        for _ in 0..n {
+            x += F::ONE;
+        }
+        x
+    }
+}
+
+/// Pack `(op, rd, rs, imm)` into the single canonical instruction id
+/// `op + rd*B + rs*B^2 + imm*B^3`, using the same Horner [`combine`] used by
+/// the RAM permutation check.
+pub fn encode_instruction<B: BackendT>(
+    backend: &mut B,
+    op: &B::Wire,
+    rd: &B::Wire,
+    rs: &B::Wire,
+    imm: &B::Wire,
+) -> Result<B::Wire>
+where
+    B::Wire: Copy,
+{
+    let radix = field_from_u64::<B::FieldElement>(RADIX);
+    // Horner takes the highest power first, so `imm` leads and `op` ends up
+    // with weight `B^0`.
+    combine(backend, [*imm, *rs, *rd, *op].iter(), radix)
+}
+
+fn field_from_u64<F: FiniteField>(n: u64) -> F {
+    let mut x = F::ZERO;
+    let mut acc = F::ONE;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            x += acc;
+        }
+        acc += acc;
+        n >>= 1;
+    }
+    x
+}
+
+/// Standard is-zero gadget: `inv_hint` is a prover-supplied witness, equal
+/// to `diff^{-1}` when `diff != 0` and to `0` otherwise (the verifier
+/// passes `None` and just gets a committed-but-unknown witness back).
+///
+/// Returns a wire `sel` that is `1` iff `diff == 0`: the constraint
+/// `diff * sel == 0` forces `diff * inv == 1` whenever `diff != 0` (so
+/// `sel == 0` in that case), and `sel == 1` whenever `diff == 0`
+/// (regardless of `inv`, since the constraint is then trivially satisfied).
+fn is_zero<B: BackendT>(
+    backend: &mut B,
+    diff: &B::Wire,
+    inv_hint: Option<B::FieldElement>,
+) -> Result<B::Wire>
+where
+    B::Wire: Copy,
+{
+    let inv = backend.input_private(inv_hint)?;
+    let prod = backend.mul(diff, &inv)?;
+    let one = backend.constant(B::FieldElement::ONE)?;
+    let sel = backend.sub(&one, &prod)?;
+    let check = backend.mul(diff, &sel)?;
+    backend.assert_zero(&check)?;
+    Ok(sel)
+}
+
+/// `sel * a + (1 - sel) * b`, i.e. select `a` when `sel == 1` and `b`
+/// otherwise, without assuming `sel` is public.
+fn select<B: BackendT>(backend: &mut B, sel: &B::Wire, a: &B::Wire, b: &B::Wire) -> Result<B::Wire>
+where
+    B::Wire: Copy,
+{
+    let diff = backend.sub(a, b)?;
+    let masked = backend.mul(sel, &diff)?;
+    backend.add(&masked, b)
+}
+
+/// Scalar convenience wrapper around [`MemoryProver::read`]/[`write`](MemoryProver::write):
+/// every cell this VM's program/data/register memories hold is a single
+/// field element, so every call site here is a width-1 access, but the
+/// underlying API takes address/value slices plus an explicit
+/// `value_width` now that `galois_ram_v0` can configure wider cells (see
+/// `plugins/ram.rs`).
+fn read1<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>(
+    mem: &mut MemoryProver<V, F, C>,
+    dmc: &mut DietMacAndCheeseProver<V, F, C>,
+    addr: &MacProver<V, F>,
+) -> Result<MacProver<V, F>>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    Ok(mem.read(dmc, &[*addr], 1)?[0])
+}
+
+fn write1<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>(
+    mem: &mut MemoryProver<V, F, C>,
+    dmc: &mut DietMacAndCheeseProver<V, F, C>,
+    addr: &MacProver<V, F>,
+    value: &MacProver<V, F>,
+) -> Result<()>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    mem.write(dmc, &[*addr], &[*value])
+}
+
+/// Verifier-side counterpart of [`read1`]/[`write1`].
+fn read1_verifier<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>(
+    mem: &mut MemoryVerifier<V, F, C>,
+    dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+    addr: &MacVerifier<F>,
+) -> Result<MacVerifier<F>>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    Ok(mem.read(dmc, &[*addr], 1)?[0])
+}
+
+fn write1_verifier<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>(
+    mem: &mut MemoryVerifier<V, F, C>,
+    dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+    addr: &MacVerifier<F>,
+    value: &MacVerifier<F>,
+) -> Result<()>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    mem.write(dmc, &[*addr], &[*value])
+}
+
+/// Prover-side register-machine execution proof.
+pub struct VmProver<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pc: MacProver<V, F>,
+    prog: MemoryProver<V, F, C>,
+    data: MemoryProver<V, F, C>,
+    regs: MemoryProver<V, F, C>,
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> VmProver<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pub fn new(pc0: MacProver<V, F>) -> Self {
+        Self {
+            pc: pc0,
+            prog: Default::default(),
+            data: Default::default(),
+            regs: Default::default(),
+        }
+    }
+
+    /// Load a program into program memory prior to execution: four
+    /// consecutive cells per instruction, `op, rd, rs, imm`.
+    pub fn load_program(
+        &mut self,
+        dmc: &mut DietMacAndCheeseProver<V, F, C>,
+        program: &[(Opcode, MacProver<V, F>, MacProver<V, F>, MacProver<V, F>)],
+    ) -> Result<()> {
+        for (i, (op, rd, rs, imm)) in program.iter().enumerate() {
+            let base = 4 * i as u64;
+            let op_wire = dmc.constant(op.code::<V>())?;
+            self.store_word(dmc, base, &op_wire)?;
+            self.store_word(dmc, base + 1, rd)?;
+            self.store_word(dmc, base + 2, rs)?;
+            self.store_word(dmc, base + 3, imm)?;
+        }
+        Ok(())
+    }
+
+    /// Instruction addresses are public (both parties agree on the layout
+    /// of the program in memory ahead of time), so they're embedded as
+    /// circuit constants rather than witnessed.
+    fn addr_const(&self, dmc: &mut DietMacAndCheeseProver<V, F, C>, n: u64) -> Result<MacProver<V, F>> {
+        dmc.constant(field_from_u64(n))
+    }
+
+    fn store_word(
+        &mut self,
+        dmc: &mut DietMacAndCheeseProver<V, F, C>,
+        addr_idx: u64,
+        value: &MacProver<V, F>,
+    ) -> Result<()> {
+        let addr = self.addr_const(dmc, addr_idx)?;
+        write1(&mut self.prog, dmc, &addr, value)
+    }
+
+    /// Run one fetch-decode-execute transition.
+    pub fn step(&mut self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+        let base = dmc.mul_constant(&self.pc, field_from_u64(4))?;
+        let addr_rd = dmc.add_constant(&base, V::ONE)?;
+        let addr_rs = dmc.add_constant(&base, field_from_u64(2))?;
+        let addr_imm = dmc.add_constant(&base, field_from_u64(3))?;
+
+        let op = read1(&mut self.prog, dmc, &base)?;
+        let rd = read1(&mut self.prog, dmc, &addr_rd)?;
+        let rs = read1(&mut self.prog, dmc, &addr_rs)?;
+        let imm = read1(&mut self.prog, dmc, &addr_imm)?;
+
+        let pc_next_default = dmc.add_constant(&self.pc, V::ONE)?;
+        let mut pc_next = pc_next_default;
+
+        for opcode in OPCODES {
+            let code = dmc.constant(opcode.code::<V>())?;
+            let diff = dmc.sub(&op, &code)?;
+            let inv_hint = {
+                let v = diff.value();
+                if v == V::ZERO { V::ZERO } else { v.inverse() }
+            };
+            let is_op = is_zero(dmc, &diff, Some(inv_hint))?;
+
+            match opcode {
+                Opcode::Load => {
+                    // masked address: `rs` when active, `rd` (any already
+                    // present cell) otherwise, so the access is a harmless
+                    // identity read on inactive branches.
+                    let addr = select(dmc, &is_op, &rs, &rd)?;
+                    let val = read1(&mut self.data, dmc, &addr)?;
+                    let old = read1(&mut self.regs, dmc, &rd)?;
+                    let new = select(dmc, &is_op, &val, &old)?;
+                    write1(&mut self.regs, dmc, &rd, &new)?;
+                }
+                Opcode::Store => {
+                    let addr = select(dmc, &is_op, &rd, &rs)?;
+                    let old = read1(&mut self.data, dmc, &addr)?;
+                    let new = select(dmc, &is_op, &rs, &old)?;
+                    write1(&mut self.data, dmc, &addr, &new)?;
+                }
+                Opcode::Add | Opcode::Sub => {
+                    let result = if opcode == Opcode::Add {
+                        dmc.add(&rs, &imm)?
+                    } else {
+                        dmc.sub(&rs, &imm)?
+                    };
+                    let old = read1(&mut self.regs, dmc, &rd)?;
+                    let new = select(dmc, &is_op, &result, &old)?;
+                    write1(&mut self.regs, dmc, &rd, &new)?;
+                }
+                Opcode::Jmp => {
+                    pc_next = select(dmc, &is_op, &imm, &pc_next)?;
+                }
+                Opcode::Beq => {
+                    let eq_diff = dmc.sub(&rd, &rs)?;
+                    let eq_inv = {
+                        let v = eq_diff.value();
+                        if v == V::ZERO { V::ZERO } else { v.inverse() }
+                    };
+                    let eq = is_zero(dmc, &eq_diff, Some(eq_inv))?;
+                    let taken = dmc.mul(&is_op, &eq)?;
+                    pc_next = select(dmc, &taken, &imm, &pc_next)?;
+                }
+            }
+        }
+
+        self.pc = pc_next;
+        Ok(())
+    }
+
+    pub fn finalize(mut self, dmc: &mut DietMacAndCheeseProver<V, F, C>) -> Result<()> {
+        self.prog.finalize(dmc)?;
+        self.data.finalize(dmc)?;
+        self.regs.finalize(dmc)
+    }
+}
+
+/// Verifier-side mirror of [`VmProver`]. Holds no secret state: every wire
+/// is committed but unknown, and dispatch is driven entirely by the
+/// selectors recovered from the `is_zero` gadget.
+pub struct VmVerifier<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pc: MacVerifier<F>,
+    prog: MemoryVerifier<V, F, C>,
+    data: MemoryVerifier<V, F, C>,
+    regs: MemoryVerifier<V, F, C>,
+}
+
+impl<V: IsSubFieldOf<F>, F: FiniteField, C: AbstractChannel> VmVerifier<V, F, C>
+where
+    F::PrimeField: IsSubFieldOf<V>,
+{
+    pub fn new(pc0: MacVerifier<F>) -> Self {
+        Self {
+            pc: pc0,
+            prog: Default::default(),
+            data: Default::default(),
+            regs: Default::default(),
+        }
+    }
+
+    /// Load a program into program memory prior to execution: mirrors
+    /// [`VmProver::load_program`]. The opcode sequence is public (both
+    /// parties agree on which program is being run); the operand wires
+    /// are whatever the caller already committed them as (typically
+    /// private witnesses).
+    pub fn load_program(
+        &mut self,
+        dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+        program: &[(Opcode, MacVerifier<F>, MacVerifier<F>, MacVerifier<F>)],
+    ) -> Result<()> {
+        for (i, (op, rd, rs, imm)) in program.iter().enumerate() {
+            let base = 4 * i as u64;
+            let op_wire = dmc.constant(op.code::<V>())?;
+            self.store_word(dmc, base, &op_wire)?;
+            self.store_word(dmc, base + 1, rd)?;
+            self.store_word(dmc, base + 2, rs)?;
+            self.store_word(dmc, base + 3, imm)?;
+        }
+        Ok(())
+    }
+
+    fn addr_const(&self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>, n: u64) -> Result<MacVerifier<F>> {
+        dmc.constant(field_from_u64(n))
+    }
+
+    fn store_word(
+        &mut self,
+        dmc: &mut DietMacAndCheeseVerifier<V, F, C>,
+        addr_idx: u64,
+        value: &MacVerifier<F>,
+    ) -> Result<()> {
+        let addr = self.addr_const(dmc, addr_idx)?;
+        write1_verifier(&mut self.prog, dmc, &addr, value)
+    }
+
+    /// Run one fetch-decode-execute transition.
+    pub fn step(&mut self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+        let base = dmc.mul_constant(&self.pc, field_from_u64(4))?;
+        let addr_rd = dmc.add_constant(&base, V::ONE)?;
+        let addr_rs = dmc.add_constant(&base, field_from_u64(2))?;
+        let addr_imm = dmc.add_constant(&base, field_from_u64(3))?;
+
+        let op = read1_verifier(&mut self.prog, dmc, &base)?;
+        let rd = read1_verifier(&mut self.prog, dmc, &addr_rd)?;
+        let rs = read1_verifier(&mut self.prog, dmc, &addr_rs)?;
+        let imm = read1_verifier(&mut self.prog, dmc, &addr_imm)?;
+
+        let pc_next_default = dmc.add_constant(&self.pc, V::ONE)?;
+        let mut pc_next = pc_next_default;
+
+        for opcode in OPCODES {
+            let code = dmc.constant(opcode.code::<V>())?;
+            let diff = dmc.sub(&op, &code)?;
+            let is_op = is_zero(dmc, &diff, None)?;
+
+            match opcode {
+                Opcode::Load => {
+                    let addr = select(dmc, &is_op, &rs, &rd)?;
+                    let val = read1_verifier(&mut self.data, dmc, &addr)?;
+                    let old = read1_verifier(&mut self.regs, dmc, &rd)?;
+                    let new = select(dmc, &is_op, &val, &old)?;
+                    write1_verifier(&mut self.regs, dmc, &rd, &new)?;
+                }
+                Opcode::Store => {
+                    let addr = select(dmc, &is_op, &rd, &rs)?;
+                    let old = read1_verifier(&mut self.data, dmc, &addr)?;
+                    let new = select(dmc, &is_op, &rs, &old)?;
+                    write1_verifier(&mut self.data, dmc, &addr, &new)?;
+                }
+                Opcode::Add | Opcode::Sub => {
+                    let result = if opcode == Opcode::Add {
+                        dmc.add(&rs, &imm)?
+                    } else {
+                        dmc.sub(&rs, &imm)?
+                    };
+                    let old = read1_verifier(&mut self.regs, dmc, &rd)?;
+                    let new = select(dmc, &is_op, &result, &old)?;
+                    write1_verifier(&mut self.regs, dmc, &rd, &new)?;
+                }
+                Opcode::Jmp => {
+                    pc_next = select(dmc, &is_op, &imm, &pc_next)?;
+                }
+                Opcode::Beq => {
+                    let eq_diff = dmc.sub(&rd, &rs)?;
+                    let eq = is_zero(dmc, &eq_diff, None)?;
+                    let taken = dmc.mul(&is_op, &eq)?;
+                    pc_next = select(dmc, &taken, &imm, &pc_next)?;
+                }
+            }
+        }
+
+        self.pc = pc_next;
+        Ok(())
+    }
+
+    pub fn finalize(mut self, dmc: &mut DietMacAndCheeseVerifier<V, F, C>) -> Result<()> {
+        self.prog.finalize(dmc)?;
+        self.data.finalize(dmc)?;
+        self.regs.finalize(dmc)
+    }
+}